@@ -36,29 +36,245 @@ pub fn method_benchmarks(c: &mut Criterion) {
         })
     });
 
+    group.bench_function("proportional approval", |b| {
+        b.iter(|| {
+            let _outcome = PAV::exact(2).outcome(&candidates, Profile::clone(&nominal_ballots));
+        })
+    });
+
+    group.bench_function("sequential proportional approval", |b| {
+        b.iter(|| {
+            let _outcome =
+                SequentialPAV::new(2).outcome(&candidates, Profile::clone(&nominal_ballots));
+        })
+    });
+
+    group.bench_function("phragmen", |b| {
+        b.iter(|| {
+            let _outcome = Phragmen::new(2).outcome(&candidates, Profile::clone(&nominal_ballots));
+        })
+    });
+
+    group.bench_function("equal shares", |b| {
+        b.iter(|| {
+            let _outcome = EqualShares::<Nominal>::new(2, Completion::Utilitarian)
+                .outcome(&candidates, Profile::clone(&nominal_ballots));
+        })
+    });
+
+    group.bench_function("block vote", |b| {
+        b.iter(|| {
+            let _outcome = LimitedVote::<Nominal>::block(2)
+                .outcome(&candidates, Profile::clone(&nominal_ballots));
+        })
+    });
+
+    group.bench_function("single non-transferable vote", |b| {
+        b.iter(|| {
+            let _outcome = LimitedVote::<Ordinal>::sntv(2)
+                .outcome(&candidates, Profile::clone(&ordinal_ballots));
+        })
+    });
+
+    group.bench_function("cumulative", |b| {
+        b.iter(|| {
+            let _outcome = Cumulative::<SingleWinner>::new(10)
+                .outcome(&candidates, Profile::clone(&cardinal_ballots));
+        })
+    });
+
+    group.bench_function("quadratic", |b| {
+        b.iter(|| {
+            let _outcome = Quadratic::<MultiWinner>::new(2, 25)
+                .outcome(&candidates, Profile::clone(&cardinal_ballots));
+        })
+    });
+
+    group.bench_function("proportional lottery", |b| {
+        b.iter(|| {
+            let _outcome =
+                ProportionalLottery.outcome(&candidates, Profile::clone(&nominal_ballots));
+        })
+    });
+
+    group.bench_function("maximal lottery", |b| {
+        b.iter(|| {
+            let _outcome = MaximalLottery.outcome(&candidates, Profile::clone(&ordinal_ballots));
+        })
+    });
+
+    group.bench_function("equal shares budgeting", |b| {
+        b.iter(|| {
+            let _outcome = EqualSharesBudgeting::<Nominal>::new(vec![1.0, 2.0, 1.5], 2.5)
+                .outcome(&candidates, Profile::clone(&nominal_ballots));
+        })
+    });
+
+    group.bench_function("phragmen budgeting", |b| {
+        b.iter(|| {
+            let _outcome = PhragmenBudgeting::new(vec![1.0, 2.0, 1.5], 2.5)
+                .outcome(&candidates, Profile::clone(&nominal_ballots));
+        })
+    });
+
     group.bench_function("borda", |b| {
         b.iter(|| {
             let _outcome = Borda.outcome(&candidates, Profile::clone(&ordinal_ballots));
         })
     });
 
+    group.bench_function("copeland", |b| {
+        b.iter(|| {
+            let _outcome =
+                Copeland::default().outcome(&candidates, Profile::clone(&ordinal_ballots));
+        })
+    });
+
+    group.bench_function("kemeny", |b| {
+        b.iter(|| {
+            let _outcome = Kemeny::default().outcome(&candidates, Profile::clone(&ordinal_ballots));
+        })
+    });
+
+    group.bench_function("minimax", |b| {
+        b.iter(|| {
+            let _outcome = Minimax::Margins.outcome(&candidates, Profile::clone(&ordinal_ballots));
+        })
+    });
+
+    group.bench_function("bucklin", |b| {
+        b.iter(|| {
+            let _outcome =
+                Bucklin::MostVotes.outcome(&candidates, Profile::clone(&ordinal_ballots));
+        })
+    });
+
+    group.bench_function("positional", |b| {
+        b.iter(|| {
+            let _outcome =
+                Positional::dowdall(3).outcome(&candidates, Profile::clone(&ordinal_ballots));
+        })
+    });
+
+    group.bench_function("chamberlin courant", |b| {
+        b.iter(|| {
+            let _outcome = ChamberlinCourant::new(2, Solver::Exact)
+                .outcome(&candidates, Profile::clone(&ordinal_ballots));
+        })
+    });
+
+    group.bench_function("monroe", |b| {
+        b.iter(|| {
+            let _outcome = Monroe::new(2, Solver::Approximate)
+                .outcome(&candidates, Profile::clone(&ordinal_ballots));
+        })
+    });
+
     group.bench_function("star", |b| {
         b.iter(|| {
             let _outcome = Star.outcome(&candidates, Profile::clone(&cardinal_ballots));
         })
     });
 
+    group.bench_function("score", |b| {
+        b.iter(|| {
+            let _outcome = Score::Total.outcome(&candidates, Profile::clone(&cardinal_ballots));
+        })
+    });
+
+    group.bench_function("majority judgment", |b| {
+        b.iter(|| {
+            let _outcome = MajorityJudgment.outcome(&candidates, Profile::clone(&cardinal_ballots));
+        })
+    });
+
     group.bench_function("instant runoff", |b| {
         b.iter(|| {
             let _outcome = IRV.outcome(&candidates, Profile::clone(&ordinal_ballots));
         })
     });
 
+    group.bench_function("ranked pairs", |b| {
+        b.iter(|| {
+            let _outcome =
+                RankedPairs::default().outcome(&candidates, Profile::clone(&ordinal_ballots));
+        })
+    });
+
+    group.bench_function("schulze", |b| {
+        b.iter(|| {
+            let _outcome = Schulze.outcome(&candidates, Profile::clone(&ordinal_ballots));
+        })
+    });
+
+    group.bench_function("two round runoff", |b| {
+        b.iter(|| {
+            let _outcome = TwoRoundRunoff.outcome(&candidates, Profile::clone(&ordinal_ballots));
+        })
+    });
+
+    group.bench_function("coombs", |b| {
+        b.iter(|| {
+            let _outcome = Coombs.outcome(&candidates, Profile::clone(&ordinal_ballots));
+        })
+    });
+
+    group.bench_function("baldwin", |b| {
+        b.iter(|| {
+            let _outcome = Baldwin.outcome(&candidates, Profile::clone(&ordinal_ballots));
+        })
+    });
+
+    group.bench_function("nanson", |b| {
+        b.iter(|| {
+            let _outcome = Nanson.outcome(&candidates, Profile::clone(&ordinal_ballots));
+        })
+    });
+
+    group.bench_function("smith irv", |b| {
+        b.iter(|| {
+            let _outcome =
+                SmithRestricted::new(IRV).outcome(&candidates, Profile::clone(&ordinal_ballots));
+        })
+    });
+
+    group.bench_function("tideman alternative", |b| {
+        b.iter(|| {
+            let _outcome =
+                TidemanAlternative.outcome(&candidates, Profile::clone(&ordinal_ballots));
+        })
+    });
+
+    group.bench_function("bottom two runoff", |b| {
+        b.iter(|| {
+            let _outcome = BottomTwoRunoff.outcome(&candidates, Profile::clone(&ordinal_ballots));
+        })
+    });
+
+    group.bench_function("dodgson", |b| {
+        b.iter(|| {
+            let _outcome =
+                Dodgson::default().outcome(&candidates, Profile::clone(&ordinal_ballots));
+        })
+    });
+
+    group.bench_function("young", |b| {
+        b.iter(|| {
+            let _outcome = Young::default().outcome(&candidates, Profile::clone(&ordinal_ballots));
+        })
+    });
+
     group.bench_function("single transferable vote", |b| {
         b.iter(|| {
             let _outcome = STV::new(2).outcome(&candidates, Profile::clone(&ordinal_ballots));
         })
     });
+
+    group.bench_function("meek single transferable vote", |b| {
+        b.iter(|| {
+            let _outcome = STV::meek(2).outcome(&candidates, Profile::clone(&ordinal_ballots));
+        })
+    });
 }
 
 criterion_group! {
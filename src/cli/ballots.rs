@@ -0,0 +1,320 @@
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::Path;
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use kingmaker::config::ConfigError;
+use kingmaker::prelude::*;
+
+use crate::cli::CliError;
+
+/// A ballot file format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum BallotFormat {
+    /// The candidates and ballots as JSON
+    Json,
+    /// One column per candidate and one row per ballot, holding ranks, approvals (1 or 0) or scores
+    Csv,
+    /// The ballot format of OpenSTV and other STV counting programs (ranked ballots only)
+    Blt,
+}
+
+impl BallotFormat {
+    /// The format given explicitly, or else the one matching the file extension.
+    pub fn resolve(format: Option<Self>, path: &Path) -> Result<Self, CliError> {
+        if let Some(format) = format {
+            return Ok(format);
+        }
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("json") => Ok(Self::Json),
+            Some("csv") => Ok(Self::Csv),
+            Some("blt") => Ok(Self::Blt),
+            _ => Err(CliError::UnknownFormat(path.display().to_string())),
+        }
+    }
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Json => "JSON",
+            Self::Csv => "CSV",
+            Self::Blt => "BLT",
+        }
+    }
+}
+
+/// A profile whose ballot type is only known at runtime.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "ballot", content = "ballots", rename_all = "lowercase")]
+pub enum AnyProfile {
+    Nominal(Profile<Nominal>),
+    Ordinal(Profile<Ordinal>),
+    Cardinal(Profile<Cardinal>),
+}
+
+/// A set of candidates and the ballots cast for them.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BallotFile {
+    pub candidates: Vec<Candidate>,
+    #[serde(flatten)]
+    pub profile: AnyProfile,
+}
+
+impl BallotFile {
+    /// Reads a ballot file, where `ballot` is the ballot type of CSV files (ranked by default).
+    pub fn read(
+        contents: &str,
+        format: BallotFormat,
+        ballot: Option<&str>,
+    ) -> Result<Self, CliError> {
+        match format {
+            BallotFormat::Json => read_json(contents),
+            BallotFormat::Csv => read_csv(contents, ballot.unwrap_or("ordinal")),
+            BallotFormat::Blt => read_blt(contents),
+        }
+    }
+    /// Writes a ballot file.
+    pub fn write(&self, format: BallotFormat) -> Result<String, CliError> {
+        match format {
+            BallotFormat::Json => Ok(serde_json::to_string_pretty(self)? + "\n"),
+            BallotFormat::Csv => write_csv(self),
+            BallotFormat::Blt => write_blt(self),
+        }
+    }
+}
+
+fn invalid(format: BallotFormat, message: impl Into<String>) -> CliError {
+    CliError::InvalidBallots {
+        format: format.name(),
+        message: message.into(),
+    }
+}
+
+fn read_json(contents: &str) -> Result<BallotFile, CliError> {
+    let file: BallotFile = serde_json::from_str(contents)?;
+    let n = file.candidates.len();
+    let error = |ballot: usize, message: &str| {
+        invalid(
+            BallotFormat::Json,
+            format!("{message} in ballot {}", ballot + 1),
+        )
+    };
+    let ballots: Vec<Vec<usize>> = match &file.profile {
+        AnyProfile::Nominal(profile) => profile
+            .iter()
+            .map(|b| b.iter().copied().collect())
+            .collect(),
+        AnyProfile::Ordinal(profile) => profile.iter().map(|b| b.0.clone()).collect(),
+        AnyProfile::Cardinal(profile) => profile
+            .iter()
+            .map(|b| b.0.keys().copied().collect())
+            .collect(),
+    };
+    for (i, ballot) in ballots.iter().enumerate() {
+        if ballot.iter().any(|&c| c >= n) {
+            return Err(error(i, "unknown candidate"));
+        }
+        if (1..ballot.len()).any(|j| ballot[..j].contains(&ballot[j])) {
+            return Err(error(i, "repeated candidate"));
+        }
+    }
+    Ok(file)
+}
+
+fn read_csv(contents: &str, ballot: &str) -> Result<BallotFile, CliError> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(contents.as_bytes());
+    let candidates: Vec<Candidate> = reader
+        .headers()?
+        .iter()
+        .enumerate()
+        .map(|(id, name)| Candidate::new(id, name, None, None))
+        .collect();
+    // The non-empty cells of each row, as (candidate, value) pairs.
+    let mut rows: Vec<Vec<(usize, usize)>> = Vec::new();
+    for (line, record) in reader.records().enumerate() {
+        let row = record?
+            .iter()
+            .enumerate()
+            .filter(|(_, cell)| !cell.is_empty())
+            .map(|(c, cell)| {
+                cell.parse()
+                    .map_err(|_| {
+                        invalid(
+                            BallotFormat::Csv,
+                            format!("`{cell}` is not a number on row {}", line + 1),
+                        )
+                    })
+                    .map(|value| (c, value))
+            })
+            .collect::<Result<_, _>>()?;
+        rows.push(row);
+    }
+    let profile = match ballot.to_ascii_lowercase().as_str() {
+        "nominal" => AnyProfile::Nominal(
+            rows.into_iter()
+                .map(|row| {
+                    Nominal(
+                        row.into_iter()
+                            .filter(|(_, v)| *v > 0)
+                            .map(|(c, _)| c)
+                            .collect(),
+                    )
+                })
+                .collect(),
+        ),
+        "ordinal" => AnyProfile::Ordinal(
+            rows.into_iter()
+                .enumerate()
+                .map(|(line, mut row)| {
+                    row.sort_by_key(|(_, rank)| *rank);
+                    if row.windows(2).any(|pair| pair[0].1 == pair[1].1) {
+                        return Err(invalid(
+                            BallotFormat::Csv,
+                            format!("tied ranks on row {}", line + 1),
+                        ));
+                    }
+                    Ok(Ordinal(row.into_iter().map(|(c, _)| c).collect()))
+                })
+                .collect::<Result<Vec<_>, _>>()?
+                .into(),
+        ),
+        "cardinal" => AnyProfile::Cardinal(
+            rows.into_iter()
+                .map(|row| Cardinal(row.into_iter().collect()))
+                .collect(),
+        ),
+        _ => return Err(ConfigError::UnknownBallot(ballot.to_string()).into()),
+    };
+    Ok(BallotFile {
+        candidates,
+        profile,
+    })
+}
+
+fn write_csv(file: &BallotFile) -> Result<String, CliError> {
+    let ids: Vec<usize> = file.candidates.iter().map(Candidate::id).collect();
+    let rows: Vec<BTreeMap<usize, usize>> = match &file.profile {
+        AnyProfile::Nominal(profile) => profile
+            .iter()
+            .map(|b| b.iter().map(|&c| (c, 1)).collect())
+            .collect(),
+        AnyProfile::Ordinal(profile) => profile
+            .iter()
+            .map(|b| {
+                b.iter()
+                    .enumerate()
+                    .map(|(rank, &c)| (c, rank + 1))
+                    .collect()
+            })
+            .collect(),
+        AnyProfile::Cardinal(profile) => profile.iter().map(|b| b.0.clone()).collect(),
+    };
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer.write_record(file.candidates.iter().map(Candidate::name))?;
+    for row in rows {
+        writer.write_record(ids.iter().map(|c| match (row.get(c), &file.profile) {
+            (Some(value), _) => value.to_string(),
+            (None, AnyProfile::Nominal(_)) => "0".to_string(),
+            (None, _) => String::new(),
+        }))?;
+    }
+    let bytes = writer.into_inner().map_err(|e| e.into_error())?;
+    Ok(String::from_utf8(bytes).expect("CSV output should be UTF-8"))
+}
+
+fn read_blt(contents: &str) -> Result<BallotFile, CliError> {
+    let error = |message: &str| invalid(BallotFormat::Blt, message);
+    let mut lines = contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty());
+    let header = lines.next().ok_or_else(|| error("the file is empty"))?;
+    let n: usize = header
+        .split_whitespace()
+        .next()
+        .and_then(|n| n.parse().ok())
+        .ok_or_else(|| error("the first line should give the number of candidates"))?;
+    let mut ballots: Vec<Ordinal> = Vec::new();
+    let mut withdrawn: Vec<usize> = Vec::new();
+    for line in lines.by_ref() {
+        // Withdrawn candidates are listed as negative numbers, and are left off every ballot.
+        if line.starts_with('-') {
+            withdrawn.extend(
+                line.split_whitespace()
+                    .filter_map(|c| c.trim_start_matches('-').parse::<usize>().ok())
+                    .map(|c| c.saturating_sub(1)),
+            );
+            continue;
+        }
+        if line == "0" {
+            break;
+        }
+        if line.contains('=') {
+            return Err(error("equal rankings are not supported"));
+        }
+        let numbers = line
+            .split_whitespace()
+            .map(str::parse::<usize>)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| error(&format!("invalid ballot `{line}`")))?;
+        let Some((&weight, ranking)) = numbers.split_first() else {
+            continue;
+        };
+        let ranking: Vec<usize> = ranking
+            .iter()
+            .take_while(|&&c| c != 0)
+            .map(|c| c - 1)
+            .collect();
+        if ranking.iter().any(|&c| c >= n) {
+            return Err(error(&format!("unknown candidate in ballot `{line}`")));
+        }
+        let ranking = ranking
+            .into_iter()
+            .filter(|c| !withdrawn.contains(c))
+            .collect();
+        ballots.extend(std::iter::repeat_n(Ordinal(ranking), weight));
+    }
+    let candidates = (0..n)
+        .map(|id| {
+            let name = lines
+                .next()
+                .ok_or_else(|| error("missing candidate names"))?;
+            Ok(Candidate::new(id, name.trim_matches('"'), None, None))
+        })
+        .collect::<Result<Vec<_>, CliError>>()?;
+    Ok(BallotFile {
+        candidates,
+        profile: AnyProfile::Ordinal(ballots.into()),
+    })
+}
+
+fn write_blt(file: &BallotFile) -> Result<String, CliError> {
+    let AnyProfile::Ordinal(profile) = &file.profile else {
+        return Err(invalid(
+            BallotFormat::Blt,
+            "only ranked ballots can be written",
+        ));
+    };
+    let position: BTreeMap<usize, usize> = file
+        .candidates
+        .iter()
+        .enumerate()
+        .map(|(position, c)| (c.id(), position + 1))
+        .collect();
+    let mut blt = format!("{} 1\n", file.candidates.len());
+    for b in profile.iter() {
+        blt.push('1');
+        for c in b.iter() {
+            let _ = write!(blt, " {}", position[c]);
+        }
+        blt.push_str(" 0\n");
+    }
+    blt.push_str("0\n");
+    for c in &file.candidates {
+        let _ = writeln!(blt, "\"{}\"", c.name());
+    }
+    blt.push_str("\"kingmaker\"\n");
+    Ok(blt)
+}
@@ -0,0 +1,380 @@
+//! The commands of the kingmaker command-line interface.
+use std::path::Path;
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use serde::Deserialize;
+use serde_json::Value;
+use thiserror::Error;
+
+use kingmaker::config::{AnyOutcome, Component, ConfigError, ElectionFile, Registry};
+use kingmaker::prelude::*;
+
+mod ballots;
+mod output;
+
+pub use ballots::{AnyProfile, BallotFile, BallotFormat};
+pub use output::{Format, Table};
+
+#[derive(Debug, Error)]
+pub enum CliError {
+    #[error(transparent)]
+    Config(#[from] ConfigError),
+    #[error("Could not access `{path}`: {source}")]
+    File {
+        path: String,
+        source: std::io::Error,
+    },
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("Invalid JSON: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("Invalid CSV: {0}")]
+    Csv(#[from] csv::Error),
+    #[error("Invalid {format} ballot file: {message}")]
+    InvalidBallots {
+        format: &'static str,
+        message: String,
+    },
+    #[error("Cannot tell the format of `{0}` from its extension (use --from or --to)")]
+    UnknownFormat(String),
+}
+
+/// Parses a method or preference model given on the command line, either as a bare name (e.g. `irv`) or as an inline TOML table (e.g. `{ name = "stv", seats = 2 }`).
+pub fn component(spec: &str) -> Result<Component, CliError> {
+    #[derive(Deserialize)]
+    struct Inline {
+        component: Component,
+    }
+    let spec = spec.trim();
+    if spec.starts_with('{') {
+        let inline: Inline =
+            toml::from_str(&format!("component = {spec}")).map_err(ConfigError::from)?;
+        Ok(inline.component)
+    } else {
+        Ok(Component::new(spec))
+    }
+}
+
+/// Reads a file, naming it in the error if it cannot be read.
+pub fn read(path: &Path) -> Result<String, CliError> {
+    std::fs::read_to_string(path).map_err(|source| CliError::File {
+        path: path.display().to_string(),
+        source,
+    })
+}
+
+/// Writes a file, naming it in the error if it cannot be written.
+pub fn write(path: &Path, contents: &str) -> Result<(), CliError> {
+    std::fs::write(path, contents).map_err(|source| CliError::File {
+        path: path.display().to_string(),
+        source,
+    })
+}
+
+/// Reads a ballot file, in the given format or else the one matching its extension.
+pub fn read_ballots(
+    path: &Path,
+    format: Option<BallotFormat>,
+    ballot: Option<&str>,
+) -> Result<BallotFile, CliError> {
+    BallotFile::read(&read(path)?, BallotFormat::resolve(format, path)?, ballot)
+}
+
+/// Simulates an election config many times and tabulates the outcomes.
+pub fn run(file: &ElectionFile, iterations: usize, seed: u64) -> Result<Table, CliError> {
+    let mut table = Table::new(vec!["outcome", "winners", "times", "share"]);
+    simulate(
+        &mut table,
+        &Registry::default(),
+        file,
+        None,
+        iterations,
+        seed,
+    )?;
+    Ok(table)
+}
+
+/// Determines the outcome of the ballots in a ballot file under a method.
+pub fn tabulate(file: &BallotFile, method: &str) -> Result<Table, CliError> {
+    compare_ballots(file, &[method.to_string()])
+}
+
+/// Runs several methods on the same profiles, drawn from an election config.
+///
+/// Since the profiles are drawn from the same seed, each method tabulates exactly the same ballots.
+pub fn compare(
+    file: &ElectionFile,
+    methods: &[String],
+    iterations: usize,
+    seed: u64,
+) -> Result<Table, CliError> {
+    let registry = Registry::default();
+    let mut table = Table::new(vec!["method", "outcome", "winners", "times", "share"]);
+    for spec in methods {
+        let mut file = file.clone();
+        file.method = component(spec)?;
+        simulate(&mut table, &registry, &file, Some(spec), iterations, seed)?;
+    }
+    Ok(table)
+}
+
+/// Runs several methods on the ballots in a ballot file.
+pub fn compare_ballots(file: &BallotFile, methods: &[String]) -> Result<Table, CliError> {
+    let registry = Registry::default();
+    let mut table = Table::new(vec!["method", "outcome", "winners"]);
+    for spec in methods {
+        let outcome = outcome(&registry, &component(spec)?, file)?;
+        table.push(vec![
+            spec.as_str().into(),
+            outcome.to_string().into(),
+            winners(&outcome),
+        ]);
+    }
+    Ok(table)
+}
+
+/// Draws a synthetic profile from a preference model.
+pub fn generate(
+    names: &[String],
+    preference: &str,
+    voters: usize,
+    ballot: &str,
+    seed: u64,
+) -> Result<BallotFile, CliError> {
+    let registry = Registry::default();
+    let preference = component(preference)?;
+    let candidates: Vec<Candidate> = names
+        .iter()
+        .enumerate()
+        .map(|(id, name)| Candidate::new(id, name, None, None))
+        .collect();
+    let n = candidates.len();
+    let mut rng = StdRng::seed_from_u64(seed);
+    let profile = match ballot.to_ascii_lowercase().as_str() {
+        "nominal" => AnyProfile::Nominal(registry.preference::<Nominal>(&preference, n)?.sample(
+            &candidates,
+            voters,
+            &mut rng,
+        )),
+        "ordinal" => AnyProfile::Ordinal(registry.preference::<Ordinal>(&preference, n)?.sample(
+            &candidates,
+            voters,
+            &mut rng,
+        )),
+        "cardinal" => AnyProfile::Cardinal(
+            registry
+                .preference::<Cardinal>(&preference, n)?
+                .sample(&candidates, voters, &mut rng),
+        ),
+        _ => return Err(ConfigError::UnknownBallot(ballot.to_string()).into()),
+    };
+    Ok(BallotFile {
+        candidates,
+        profile,
+    })
+}
+
+/// Runs an election config many times, adding a row to the table for each distinct outcome.
+fn simulate(
+    table: &mut Table,
+    registry: &Registry,
+    file: &ElectionFile,
+    method: Option<&str>,
+    iterations: usize,
+    seed: u64,
+) -> Result<(), CliError> {
+    let election = registry.election(file)?;
+    let mut tabulated = election.tabulate(election.run_many(iterations, seed));
+    tabulated.sort_by(|(_, a), (_, b)| b.cmp(a));
+    for (outcome, times) in tabulated {
+        let share = f64::from(u32::try_from(times).unwrap_or(u32::MAX))
+            / f64::from(u32::try_from(iterations).unwrap_or(u32::MAX));
+        let row = [
+            outcome.to_string().into(),
+            winners(&outcome),
+            times.into(),
+            share.into(),
+        ];
+        table.push(method.map(Value::from).into_iter().chain(row).collect());
+    }
+    Ok(())
+}
+
+/// Determines the outcome of a ballot file under a method.
+fn outcome(
+    registry: &Registry,
+    method: &Component,
+    file: &BallotFile,
+) -> Result<AnyOutcome, CliError> {
+    let candidates = &file.candidates;
+    Ok(match &file.profile {
+        AnyProfile::Nominal(profile) => registry
+            .method::<Nominal>(method)?
+            .outcome(candidates, profile.clone()),
+        AnyProfile::Ordinal(profile) => registry
+            .method::<Ordinal>(method)?
+            .outcome(candidates, profile.clone()),
+        AnyProfile::Cardinal(profile) => registry
+            .method::<Cardinal>(method)?
+            .outcome(candidates, profile.clone()),
+    })
+}
+
+fn winners(outcome: &AnyOutcome) -> Value {
+    outcome.winners().into_iter().map(Value::from).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = r#"
+        [[candidates]]
+        name = "A"
+
+        [[candidates]]
+        name = "B"
+
+        [[candidates]]
+        name = "C"
+
+        [[voting_blocs]]
+        members = 31
+        preference = { name = "mallows", reference = [0, 1, 2], phi = 0.3 }
+
+        [[voting_blocs]]
+        members = 30
+        preference = { name = "mallows", reference = [1, 2, 0], phi = 0.3 }
+
+        [method]
+        name = "plurality"
+    "#;
+
+    fn ballots() -> BallotFile {
+        BallotFile {
+            candidates: ["A", "B", "C"]
+                .iter()
+                .enumerate()
+                .map(|(id, name)| Candidate::new(id, name, None, None))
+                .collect(),
+            profile: AnyProfile::Ordinal(
+                [
+                    vec![0, 1, 2],
+                    vec![0, 2],
+                    vec![1, 2, 0],
+                    vec![2, 1, 0],
+                    vec![1],
+                ]
+                .into_iter()
+                .map(Ordinal)
+                .collect(),
+            ),
+        }
+    }
+
+    #[test]
+    fn cli_ballot_formats() {
+        let file = ballots();
+        for format in [BallotFormat::Json, BallotFormat::Csv, BallotFormat::Blt] {
+            let written = file.write(format).unwrap();
+            assert_eq!(BallotFile::read(&written, format, None).unwrap(), file);
+        }
+        let csv = "A,B,C\n1,,2\n2,1,3\n";
+        let read = BallotFile::read(csv, BallotFormat::Csv, None).unwrap();
+        assert_eq!(
+            read.profile,
+            AnyProfile::Ordinal(vec![Ordinal(vec![0, 2]), Ordinal(vec![1, 0, 2])].into())
+        );
+        let read = BallotFile::read(csv, BallotFormat::Csv, Some("cardinal")).unwrap();
+        assert_eq!(read.write(BallotFormat::Csv).unwrap(), csv);
+        assert!(BallotFile::read("A,B\n1,1\n", BallotFormat::Csv, None).is_err());
+        assert!(read.write(BallotFormat::Blt).is_err());
+
+        let json = r#"{"candidates":[{"id":0,"name":"A"},{"id":1,"name":"B"}],"ballot":"ordinal","ballots":[[0,1]]}"#;
+        assert!(BallotFile::read(json, BallotFormat::Json, None).is_ok());
+        for ballots in ["[[0,4]]", "[[1,1]]"] {
+            let json = json.replace("[[0,1]]", ballots);
+            assert!(matches!(
+                BallotFile::read(&json, BallotFormat::Json, None),
+                Err(CliError::InvalidBallots { format: "JSON", .. })
+            ));
+        }
+
+        let blt = "3 1\n-2\n2 1 2 3 0\n1 3 0\n0\n\"A\"\n\"B\"\n\"C\"\n\"Title\"\n";
+        let read = BallotFile::read(blt, BallotFormat::Blt, None).unwrap();
+        assert_eq!(read.candidates[2].name(), "C");
+        assert_eq!(
+            read.profile,
+            AnyProfile::Ordinal(
+                vec![Ordinal(vec![0, 2]), Ordinal(vec![0, 2]), Ordinal(vec![2])].into()
+            )
+        );
+    }
+
+    #[test]
+    fn cli_tabulate() {
+        let table = tabulate(&ballots(), "irv").unwrap();
+        assert_eq!(table.rows()[0][2], Value::from(vec!["B"]));
+        let table = compare_ballots(
+            &ballots(),
+            &["plurality".into(), "{ name = \"stv\", seats = 2 }".into()],
+        )
+        .unwrap();
+        assert_eq!(table.rows().len(), 2);
+        assert_eq!(table.rows()[1][2], Value::from(vec!["A", "B"]));
+        assert!(matches!(
+            tabulate(&ballots(), "approval"),
+            Err(CliError::Config(ConfigError::UnsupportedBallot { .. }))
+        ));
+        let csv = table.render(Format::Csv).unwrap();
+        assert!(csv.starts_with("method,outcome,winners\n"));
+        let text = table.render(Format::Table).unwrap();
+        assert_eq!(text.lines().count(), 4);
+    }
+
+    #[test]
+    fn cli_run_and_compare() {
+        let file = ElectionFile::from_toml(CONFIG).unwrap();
+        let table = run(&file, 50, 0).unwrap();
+        let times: u64 = table
+            .rows()
+            .iter()
+            .map(|row| row[2].as_u64().unwrap())
+            .sum();
+        assert_eq!(times, 50);
+
+        let methods = ["plurality".to_string(), "borda".to_string()];
+        let table = compare(&file, &methods, 50, 0).unwrap();
+        let plurality: Vec<_> = table
+            .rows()
+            .iter()
+            .filter(|row| row[0] == "plurality")
+            .map(|row| row[1..].to_vec())
+            .collect();
+        let runs: Vec<_> = run(&file, 50, 0).unwrap().rows().to_vec();
+        assert_eq!(plurality, runs);
+        let json: Value = serde_json::from_str(&table.render(Format::Json).unwrap()).unwrap();
+        assert_eq!(json.as_array().unwrap().len(), table.rows().len());
+    }
+
+    #[test]
+    fn cli_generate() {
+        let names = ["A".to_string(), "B".to_string(), "C".to_string()];
+        let file = generate(
+            &names,
+            "{ name = \"mallows\", reference = [2, 1, 0], phi = 0.1 }",
+            20,
+            "ordinal",
+            0,
+        )
+        .unwrap();
+        assert!(matches!(file.profile, AnyProfile::Ordinal(profile) if profile.len() == 20));
+        let nominal = generate(&names, "impartial", 20, "nominal", 0).unwrap();
+        assert!(matches!(nominal.profile, AnyProfile::Nominal(profile) if profile.len() == 20));
+        let cardinal = generate(&names, "impartial", 5, "cardinal", 0).unwrap();
+        assert!(matches!(cardinal.profile, AnyProfile::Cardinal(_)));
+        assert!(generate(&names, "mallows", 5, "ordinal", 0).is_err());
+        assert!(generate(&names, "impartial", 5, "ranked", 0).is_err());
+    }
+}
@@ -0,0 +1,113 @@
+use clap::ValueEnum;
+use serde_json::{Map, Value};
+
+use crate::cli::CliError;
+
+/// An output format for results.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// An aligned, human-readable table
+    #[default]
+    Table,
+    /// A JSON array with one object per row
+    Json,
+    /// CSV with a header row
+    Csv,
+}
+
+/// A table of results, with one column per header.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Table {
+    headers: Vec<&'static str>,
+    rows: Vec<Vec<Value>>,
+}
+
+impl Table {
+    pub const fn new(headers: Vec<&'static str>) -> Self {
+        Self {
+            headers,
+            rows: Vec::new(),
+        }
+    }
+    pub fn push(&mut self, row: Vec<Value>) {
+        debug_assert_eq!(row.len(), self.headers.len());
+        self.rows.push(row);
+    }
+    #[cfg(test)]
+    pub fn rows(&self) -> &[Vec<Value>] {
+        &self.rows
+    }
+    /// Renders the table in the given format.
+    pub fn render(&self, format: Format) -> Result<String, CliError> {
+        match format {
+            Format::Table => Ok(self.text()),
+            Format::Json => {
+                let rows: Vec<Map<String, Value>> = self
+                    .rows
+                    .iter()
+                    .map(|row| {
+                        self.headers
+                            .iter()
+                            .map(|header| (*header).to_string())
+                            .zip(row.iter().cloned())
+                            .collect()
+                    })
+                    .collect();
+                Ok(serde_json::to_string_pretty(&rows)? + "\n")
+            }
+            Format::Csv => {
+                let mut writer = csv::Writer::from_writer(Vec::new());
+                writer.write_record(&self.headers)?;
+                for row in &self.rows {
+                    writer.write_record(row.iter().map(cell))?;
+                }
+                let bytes = writer.into_inner().map_err(|e| e.into_error())?;
+                Ok(String::from_utf8(bytes).expect("CSV output should be UTF-8"))
+            }
+        }
+    }
+    fn text(&self) -> String {
+        let rows: Vec<Vec<String>> = self
+            .rows
+            .iter()
+            .map(|row| row.iter().map(cell).collect())
+            .collect();
+        let widths: Vec<usize> = (0..self.headers.len())
+            .map(|i| {
+                rows.iter()
+                    .map(|row| row[i].chars().count())
+                    .chain(std::iter::once(self.headers[i].len()))
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+        let line = |cells: Vec<&str>| {
+            cells
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:<width$}"))
+                .collect::<Vec<_>>()
+                .join("  ")
+                .trim_end()
+                .to_string()
+                + "\n"
+        };
+        let mut text = line(self.headers.clone());
+        let rules: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+        text += &line(rules.iter().map(String::as_str).collect());
+        for row in &rows {
+            text += &line(row.iter().map(String::as_str).collect());
+        }
+        text
+    }
+}
+
+/// The text of a cell, where lists are joined with commas.
+fn cell(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        Value::Null => String::new(),
+        Value::Array(values) => values.iter().map(cell).collect::<Vec<_>>().join(", "),
+        value => value.to_string(),
+    }
+}
@@ -0,0 +1,243 @@
+use std::fmt::{self, Debug, Display};
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::config::{Component, ConfigError, Describe};
+use crate::core::{
+    Ballot, Candidate, Cardinal, Election, Lottery, Method, MultiWinner, Nominal, Ordinal, Outcome,
+    Profile, SingleWinner, TieBreaking, Ties,
+};
+
+/// The outcome of a method whose type is only known at runtime.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AnyOutcome {
+    /// The outcome of a single-winner method
+    SingleWinner(SingleWinner),
+    /// The outcome of a multi-winner method
+    MultiWinner(MultiWinner),
+    /// The outcome of a lottery method
+    Lottery(Lottery),
+}
+
+impl Outcome for AnyOutcome {
+    fn winners(&self) -> Vec<&str> {
+        match self {
+            Self::SingleWinner(outcome) => outcome.winners(),
+            Self::MultiWinner(outcome) => outcome.winners(),
+            Self::Lottery(outcome) => outcome.winners(),
+        }
+    }
+    fn break_tie(self, ties: &mut Ties) -> Self {
+        match self {
+            Self::SingleWinner(outcome) => Self::SingleWinner(outcome.break_tie(ties)),
+            outcome => outcome,
+        }
+    }
+    fn relabel(self, candidates: &[Candidate]) -> Self {
+        match self {
+            Self::SingleWinner(outcome) => Self::SingleWinner(outcome.relabel(candidates)),
+            Self::MultiWinner(outcome) => Self::MultiWinner(outcome.relabel(candidates)),
+            Self::Lottery(outcome) => Self::Lottery(outcome.relabel(candidates)),
+        }
+    }
+}
+
+impl Display for AnyOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::SingleWinner(outcome) => Display::fmt(outcome, f),
+            Self::MultiWinner(outcome) => Display::fmt(outcome, f),
+            Self::Lottery(outcome) => Display::fmt(outcome, f),
+        }
+    }
+}
+
+impl From<SingleWinner> for AnyOutcome {
+    fn from(outcome: SingleWinner) -> Self {
+        Self::SingleWinner(outcome)
+    }
+}
+
+impl From<MultiWinner> for AnyOutcome {
+    fn from(outcome: MultiWinner) -> Self {
+        Self::MultiWinner(outcome)
+    }
+}
+
+impl From<Lottery> for AnyOutcome {
+    fn from(outcome: Lottery) -> Self {
+        Self::Lottery(outcome)
+    }
+}
+
+/// A method with its outcome type erased, so that it can be stored behind a trait object.
+trait ErasedMethod<B: Ballot>: Send + Sync + Debug {
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<B>) -> AnyOutcome;
+    fn outcome_with(
+        &self,
+        candidates: &[Candidate],
+        profile: Profile<B>,
+        ties: &mut Ties,
+    ) -> AnyOutcome;
+    fn describe(&self) -> Component;
+}
+
+impl<M> ErasedMethod<M::Ballot> for M
+where
+    M: Method + Describe,
+    M::Winner: Into<AnyOutcome>,
+{
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<M::Ballot>) -> AnyOutcome {
+        Method::outcome(self, candidates, profile).into()
+    }
+    fn outcome_with(
+        &self,
+        candidates: &[Candidate],
+        profile: Profile<M::Ballot>,
+        ties: &mut Ties,
+    ) -> AnyOutcome {
+        Method::outcome_with(self, candidates, profile, ties).into()
+    }
+    fn describe(&self) -> Component {
+        Describe::describe(self)
+    }
+}
+
+/// A method whose type is only known at runtime, e.g. one built from a config file.
+///
+/// It is itself a method for its ballot type, so it can be used anywhere a method can (including inside other methods, such as `SmithRestricted`).
+#[derive(Clone)]
+pub struct AnyMethod<B: Ballot>(Arc<dyn ErasedMethod<B>>);
+
+impl<B: Ballot> AnyMethod<B> {
+    /// Erases the type of a method.
+    pub fn new<M>(method: M) -> Self
+    where
+        M: Method<Ballot = B> + Describe + 'static,
+        M::Winner: Into<AnyOutcome>,
+    {
+        Self(Arc::new(method))
+    }
+}
+
+impl<B: Ballot> Debug for AnyMethod<B> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+impl<B: Ballot> Describe for AnyMethod<B> {
+    fn describe(&self) -> Component {
+        self.0.describe()
+    }
+}
+
+impl<M: Method + Describe> Describe for TieBreaking<M> {
+    fn describe(&self) -> Component {
+        self.method()
+            .describe()
+            .param("tie_breaker", json!(self.tie_breaker()))
+    }
+}
+
+impl<B: Ballot> Method for AnyMethod<B> {
+    type Ballot = B;
+    type Winner = AnyOutcome;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        self.0.outcome(candidates, profile)
+    }
+    #[inline]
+    fn outcome_with(
+        &self,
+        candidates: &[Candidate],
+        profile: Profile<Self::Ballot>,
+        ties: &mut Ties,
+    ) -> Self::Winner {
+        self.0.outcome_with(candidates, profile, ties)
+    }
+}
+
+/// An election whose ballot type and method are only known at runtime, e.g. one built from a config file.
+#[derive(Debug)]
+pub enum AnyElection {
+    /// An election with approval ballots
+    Nominal(Election<Nominal, AnyMethod<Nominal>>),
+    /// An election with ranked ballots
+    Ordinal(Election<Ordinal, AnyMethod<Ordinal>>),
+    /// An election with score ballots
+    Cardinal(Election<Cardinal, AnyMethod<Cardinal>>),
+}
+
+impl AnyElection {
+    /// Get the name of the ballot type cast in the election
+    #[must_use]
+    pub const fn ballot(&self) -> &'static str {
+        match self {
+            Self::Nominal(_) => Nominal::NAME,
+            Self::Ordinal(_) => Ordinal::NAME,
+            Self::Cardinal(_) => Cardinal::NAME,
+        }
+    }
+    /// Get the candidates up for election
+    #[must_use]
+    pub fn candidates(&self) -> &[Candidate] {
+        match self {
+            Self::Nominal(election) => election.candidates(),
+            Self::Ordinal(election) => election.candidates(),
+            Self::Cardinal(election) => election.candidates(),
+        }
+    }
+    /// Run a single election with the given configuration
+    #[must_use]
+    pub fn run_once(&self, seed: u64) -> AnyOutcome {
+        match self {
+            Self::Nominal(election) => election.run_once(seed),
+            Self::Ordinal(election) => election.run_once(seed),
+            Self::Cardinal(election) => election.run_once(seed),
+        }
+    }
+    /// Run many elections with the given configuration
+    #[must_use]
+    pub fn run_many(&self, iterations: usize, seed: u64) -> Vec<AnyOutcome> {
+        match self {
+            Self::Nominal(election) => election.run_many(iterations, seed),
+            Self::Ordinal(election) => election.run_many(iterations, seed),
+            Self::Cardinal(election) => election.run_many(iterations, seed),
+        }
+    }
+    /// Tabulates the outcomes of the elections
+    pub fn tabulate(
+        &self,
+        outcomes: impl IntoIterator<Item = AnyOutcome>,
+    ) -> Vec<(AnyOutcome, usize)> {
+        match self {
+            Self::Nominal(election) => election.tabulate(outcomes),
+            Self::Ordinal(election) => election.tabulate(outcomes),
+            Self::Cardinal(election) => election.tabulate(outcomes),
+        }
+    }
+    /// Writes the election as a JSON config file, which can be read back with `Election::from_json` or `ElectionFile::from_json`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a voting bloc uses a preference model or tactic that is not in the default registry.
+    pub fn to_json(&self) -> Result<String, ConfigError> {
+        match self {
+            Self::Nominal(election) => election.to_json(),
+            Self::Ordinal(election) => election.to_json(),
+            Self::Cardinal(election) => election.to_json(),
+        }
+    }
+    /// Writes the configuration and outcomes of the election as JSON
+    #[must_use]
+    pub fn write(self, outcomes: Vec<AnyOutcome>) -> serde_json::Value {
+        match self {
+            Self::Nominal(election) => election.write(outcomes),
+            Self::Ordinal(election) => election.write(outcomes),
+            Self::Cardinal(election) => election.write(outcomes),
+        }
+    }
+}
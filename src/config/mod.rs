@@ -0,0 +1,710 @@
+//! Declarative election configs, read from TOML or JSON files, and the `Registry` of methods, preferences and tactics they refer to by name.
+//!
+//! An election config lists the candidates, the voting blocs (each with a preference model and weighted tactics) and the method, e.g.
+//!
+//! ```toml
+//! [[candidates]]
+//! name = "Alice"
+//! party = "DEM"
+//!
+//! [[candidates]]
+//! name = "Bob"
+//!
+//! [[voting_blocs]]
+//! members = 40
+//! preference = { name = "mallows", reference = [0, 1], phi = 0.5 }
+//! tactics = [{ name = "burial", candidates = [1], weight = 0.8 }, { name = "identity", weight = 0.2 }]
+//!
+//! [method]
+//! name = "irv"
+//! ```
+//!
+//! Candidate ids default to their position in the list. The ballot type can be given with `ballot = "ordinal"`, and otherwise is inferred from the method.
+use std::path::Path;
+
+use ordered_float::NotNan;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use thiserror::Error;
+
+use crate::core::{Ballot, Candidate, Election, ElectionError, Id, Method};
+
+mod erased;
+mod registry;
+
+pub use erased::{AnyElection, AnyMethod, AnyOutcome};
+pub use registry::{Params, Registry};
+
+/// The config of an election, as read from a config file or written by `Election::to_json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ElectionFile {
+    /// The ballot type cast in the election (`"nominal"`, `"ordinal"` or `"cardinal"`), inferred from the method if missing
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ballot: Option<String>,
+    /// The candidates up for election
+    pub candidates: Vec<CandidateSpec>,
+    /// The voting blocs
+    pub voting_blocs: Vec<BlocSpec>,
+    /// The method used to determine the winner of the election
+    pub method: Component,
+}
+
+impl ElectionFile {
+    /// Reads an election config from TOML.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the TOML is malformed or does not describe an election.
+    pub fn from_toml(toml: &str) -> Result<Self, ConfigError> {
+        Ok(toml::from_str(toml)?)
+    }
+    /// Reads an election config from JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON is malformed or does not describe an election.
+    pub fn from_json(json: &str) -> Result<Self, ConfigError> {
+        Ok(serde_json::from_str(json)?)
+    }
+    /// Reads an election config from a file, as JSON if it has a `.json` extension and as TOML otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or does not describe an election.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)?;
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("json") => Self::from_json(&contents),
+            _ => Self::from_toml(&contents),
+        }
+    }
+    /// Builds the election with the default registry.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the election cannot be built (see `Registry::election`).
+    pub fn build(&self) -> Result<AnyElection, ConfigError> {
+        Registry::default().election(self)
+    }
+}
+
+impl<B, M> Election<B, M>
+where
+    B: Ballot,
+    M: Method<Ballot = B> + Describe,
+{
+    /// Writes the election as a JSON config file, which can be read back with `Election::from_json` (or `ElectionFile::from_json`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a voting bloc uses a preference model or tactic that is not in the default registry.
+    pub fn to_json(&self) -> Result<String, ConfigError> {
+        let file = Registry::default().describe_election(self)?;
+        Ok(serde_json::to_string(&file)?)
+    }
+}
+
+impl<B: Ballot> Election<B, AnyMethod<B>> {
+    /// Reads an election from a JSON config file, as written by `Election::to_json`, and builds it with the default registry.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON is malformed, the config is for another ballot type, or the election cannot be built (see `Registry::election`).
+    pub fn from_json(json: &str) -> Result<Self, ConfigError> {
+        let file = ElectionFile::from_json(json)?;
+        if let Some(ballot) = file
+            .ballot
+            .as_ref()
+            .filter(|ballot| !ballot.eq_ignore_ascii_case(B::NAME))
+        {
+            return Err(ConfigError::MismatchedBallot {
+                ballot: ballot.clone(),
+                expected: B::NAME,
+            });
+        }
+        Registry::default().typed_election(&file)
+    }
+}
+
+/// A candidate in an election config.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CandidateSpec {
+    /// The id of the candidate, which defaults to their position in the list of candidates
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Id>,
+    /// The name of the candidate
+    pub name: String,
+    /// The party the candidate is associated with
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub party: Option<String>,
+    /// The positions the candidate holds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub positions: Option<Vec<f32>>,
+}
+
+impl CandidateSpec {
+    /// Builds the candidate at the given position in the list of candidates.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the candidate's positions is NaN.
+    pub fn candidate(&self, position: usize) -> Result<Candidate, ConfigError> {
+        if self.positions.iter().flatten().any(|p| p.is_nan()) {
+            return Err(ConfigError::InvalidPosition(self.name.clone()));
+        }
+        Ok(Candidate::new(
+            self.id.unwrap_or(position),
+            &self.name,
+            self.party.as_deref(),
+            self.positions.clone(),
+        ))
+    }
+}
+
+impl From<&Candidate> for CandidateSpec {
+    fn from(candidate: &Candidate) -> Self {
+        Self {
+            id: Some(candidate.id()),
+            name: candidate.name().to_string(),
+            party: candidate.party().map(str::to_string),
+            positions: candidate
+                .positions()
+                .map(|positions| positions.into_iter().map(NotNan::into_inner).collect()),
+        }
+    }
+}
+
+/// A voting bloc in an election config.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BlocSpec {
+    /// The number of members in the voting bloc
+    pub members: usize,
+    /// The preference model of the voting bloc
+    pub preference: Component,
+    /// The weighted tactics of the voting bloc, which default to voting honestly
+    #[serde(default)]
+    pub tactics: Vec<WeightedComponent>,
+}
+
+/// A method, preference model or tactic in an election config, given by its registered name and parameters.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Component {
+    /// The registered name
+    pub name: String,
+    /// The parameters, e.g. the `seats` of a method
+    #[serde(flatten)]
+    pub params: Map<String, Value>,
+}
+
+impl Component {
+    /// Creates a component without parameters.
+    #[must_use]
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            params: Map::new(),
+        }
+    }
+    /// Adds a parameter to the component.
+    #[must_use]
+    pub fn param(mut self, key: &str, value: impl Into<Value>) -> Self {
+        self.params.insert(key.to_string(), value.into());
+        self
+    }
+}
+
+/// A tactic in an election config, with the share of the voting bloc that uses it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WeightedComponent {
+    /// The weight of the tactic
+    #[serde(default = "default_weight")]
+    pub weight: f32,
+    /// The tactic
+    #[serde(flatten)]
+    pub component: Component,
+}
+
+const fn default_weight() -> f32 {
+    1.0
+}
+
+/// A method, preference model or tactic that can be written to an election config, as the component the registry builds it from.
+///
+/// Everything in a registry implements it, so that an election built from its components can be written back to a config (see `Registry::describe_election`).
+pub trait Describe {
+    /// The component that builds this, e.g. `{ name = "burial", candidates = [1] }`.
+    fn describe(&self) -> Component;
+}
+
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("Could not read the config file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Invalid TOML: {0}")]
+    Toml(#[from] toml::de::Error),
+    #[error("Invalid JSON: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("Unknown {kind} `{name}` (expected one of: {known})")]
+    Unknown {
+        kind: &'static str,
+        name: String,
+        known: String,
+    },
+    #[error("Unknown ballot type `{0}` (expected one of: nominal, ordinal, cardinal)")]
+    UnknownBallot(String),
+    #[error("The {kind} `{name}` does not support {ballot} ballots")]
+    UnsupportedBallot {
+        kind: &'static str,
+        name: String,
+        ballot: &'static str,
+    },
+    #[error(
+        "The method `{name}` supports several ballot types ({ballots}), so `ballot` must be given"
+    )]
+    AmbiguousBallot { name: String, ballots: String },
+    #[error("Unknown parameter `{parameter}` for the {kind} `{name}` (expected: {expected})")]
+    UnknownParameter {
+        kind: &'static str,
+        name: String,
+        parameter: String,
+        expected: String,
+    },
+    #[error("Missing parameter `{parameter}` for the {kind} `{name}`")]
+    MissingParameter {
+        kind: &'static str,
+        name: String,
+        parameter: &'static str,
+    },
+    #[error("Invalid parameter `{parameter}` for the {kind} `{name}`: {message}")]
+    InvalidParameter {
+        kind: &'static str,
+        name: String,
+        parameter: &'static str,
+        message: String,
+    },
+    #[error("The config is for {ballot} ballots, not {expected} ballots")]
+    MismatchedBallot {
+        ballot: String,
+        expected: &'static str,
+    },
+    #[error("The {kind} {name} is not registered, so it cannot be written to a config")]
+    Unregistered { kind: &'static str, name: String },
+    #[error("Candidate `{0}` has a position that is not a number")]
+    InvalidPosition(String),
+    #[error(transparent)]
+    Election(#[from] ElectionError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    const CONFIG: &str = r#"
+        [[candidates]]
+        name = "Alice"
+        party = "DEM"
+
+        [[candidates]]
+        name = "Bob"
+        party = "REP"
+
+        [[candidates]]
+        name = "Cybil"
+
+        [[voting_blocs]]
+        members = 40
+        preference = { name = "mallows", reference = [0, 2, 1], phi = 0.5 }
+        tactics = [{ name = "burial", candidates = [1], weight = 0.8 }, { name = "identity", weight = 0.2 }]
+
+        [[voting_blocs]]
+        members = 45
+        preference = { name = "mallows", reference = [1, 2, 0], phi = 0.5 }
+
+        [[voting_blocs]]
+        members = 15
+        preference = { name = "impartial" }
+        tactics = [{ name = "compromise", candidates = [0] }]
+
+        [method]
+        name = "smith"
+        method = { name = "irv" }
+    "#;
+
+    fn error(file: &ElectionFile) -> ConfigError {
+        file.build().expect_err("The config should be invalid")
+    }
+
+    #[test]
+    fn config_election() {
+        let file = ElectionFile::from_toml(CONFIG).unwrap();
+        let election = file.build().unwrap();
+        assert_eq!(election.ballot(), "Ordinal");
+        assert_eq!(election.candidates()[1].party(), Some("REP"));
+        let outcomes = election.run_many(20, 0);
+        let total: usize = election.tabulate(outcomes).iter().map(|(_, n)| n).sum();
+        assert_eq!(total, 20);
+
+        // The same config as JSON builds the same election.
+        let json = ElectionFile::from_json(&serde_json::to_string(&file).unwrap()).unwrap();
+        assert_eq!(json.build().unwrap().run_once(3), election.run_once(3));
+
+        // Every method accepts a tie-breaker.
+        let mut tied = file.clone();
+        tied.method = Component::new("stv")
+            .param("seats", 2)
+            .param("tie_breaker", serde_json::json!({ "order": [2, 1, 0] }));
+        assert!(tied.build().is_ok());
+        tied.method = Component::new("borda").param("tie_breaker", "backwards");
+        assert!(tied.build().is_ok());
+
+        // Methods with several ballot types need the ballot type to be given.
+        let mut file = file;
+        file.voting_blocs.truncate(1);
+        file.voting_blocs[0].tactics.clear();
+        file.voting_blocs[0].preference = Component::new("impartial");
+        file.method = Component::new("sntv").param("seats", 2);
+        assert!(matches!(error(&file), ConfigError::AmbiguousBallot { .. }));
+        file.ballot = Some("nominal".to_string());
+        assert!(matches!(file.build(), Ok(AnyElection::Nominal(_))));
+
+        // Approval voters can bullet vote too.
+        file.voting_blocs[0].tactics.push(WeightedComponent {
+            weight: 1.0,
+            component: Component::new("bullet").param("candidates", [0]),
+        });
+        file.method = Component::new("phragmen_budgeting")
+            .param("costs", vec![1.0, 1.0, 1.0])
+            .param("budget", 2.0);
+        assert!(matches!(file.build(), Ok(AnyElection::Nominal(_))));
+    }
+
+    #[test]
+    fn config_errors() {
+        let mut file = ElectionFile::from_toml(CONFIG).unwrap();
+        file.method = Component::new("instant_runoff");
+        assert!(matches!(
+            error(&file),
+            ConfigError::Unknown { kind: "method", .. }
+        ));
+
+        file.method = Component::new("irv").param("seats", 2);
+        assert!(matches!(error(&file), ConfigError::UnknownParameter { .. }));
+
+        file.method = Component::new("stv");
+        assert!(matches!(
+            error(&file),
+            ConfigError::MissingParameter {
+                parameter: "seats",
+                ..
+            }
+        ));
+
+        file.method = Component::new("stv").param("seats", "two");
+        assert!(matches!(error(&file), ConfigError::InvalidParameter { .. }));
+
+        file.method = Component::new("stv")
+            .param("seats", 2)
+            .param("rule", "hare");
+        assert!(matches!(error(&file), ConfigError::InvalidParameter { .. }));
+
+        file.method = Component::new("approval");
+        assert!(matches!(
+            error(&file),
+            ConfigError::UnsupportedBallot {
+                kind: "preference",
+                ..
+            }
+        ));
+
+        file.method = Component::new("limited_vote")
+            .param("seats", 2)
+            .param("votes", 2);
+        file.ballot = Some("ordinal".to_string());
+        assert!(matches!(
+            error(&file),
+            ConfigError::InvalidParameter {
+                parameter: "votes",
+                ..
+            }
+        ));
+        file.ballot = None;
+
+        file.method = Component::new("party_list")
+            .param("seats", 2)
+            .param("quota", "droop");
+        assert!(matches!(
+            error(&file),
+            ConfigError::InvalidParameter {
+                parameter: "quota",
+                ..
+            }
+        ));
+
+        file.method = Component::new("kemeny").param("limit", 64);
+        assert!(matches!(
+            error(&file),
+            ConfigError::InvalidParameter {
+                parameter: "limit",
+                ..
+            }
+        ));
+
+        file.method = Component::new("irv").param("tie_breaker", "coin");
+        assert!(matches!(
+            error(&file),
+            ConfigError::InvalidParameter {
+                parameter: "tie_breaker",
+                ..
+            }
+        ));
+
+        file.method = Component::new("irv");
+        let blocs = file.voting_blocs.clone();
+        for (bloc, component, parameter) in [
+            (
+                0,
+                Component::new("mallows")
+                    .param("reference", [0, 1, 5])
+                    .param("phi", 0.5),
+                "reference",
+            ),
+            (
+                0,
+                Component::new("mallows")
+                    .param("reference", [0, 1])
+                    .param("phi", 0.5),
+                "reference",
+            ),
+            (
+                1,
+                Component::new("manual").param("ballots", [[0, 7]]),
+                "ballots",
+            ),
+        ] {
+            file.voting_blocs[bloc].preference = component;
+            assert!(matches!(
+                error(&file),
+                ConfigError::InvalidParameter { parameter: p, .. } if p == parameter
+            ));
+            file.voting_blocs.clone_from(&blocs);
+        }
+        file.voting_blocs[0].tactics[0].component =
+            Component::new("burial").param("candidates", [9]);
+        assert!(matches!(
+            error(&file),
+            ConfigError::InvalidParameter {
+                parameter: "candidates",
+                ..
+            }
+        ));
+        file.voting_blocs = blocs;
+
+        file.ballot = Some("ranked".to_string());
+        assert!(matches!(error(&file), ConfigError::UnknownBallot(_)));
+
+        assert!(matches!(
+            ElectionFile::from_toml("candidates = 3"),
+            Err(ConfigError::Toml(_))
+        ));
+    }
+
+    #[test]
+    fn config_custom_registry() {
+        let mut registry = Registry::empty();
+        registry
+            .register_method("first", &[], |_| Ok(methods::Plurality))
+            .register_preference::<Ordinal, _, _>("uniform", &[], |_| Ok(preferences::Impartial));
+        let file = ElectionFile {
+            ballot: None,
+            candidates: vec![CandidateSpec {
+                id: None,
+                name: "A".to_string(),
+                party: None,
+                positions: None,
+            }],
+            voting_blocs: vec![BlocSpec {
+                members: 5,
+                preference: Component::new("uniform"),
+                tactics: vec![],
+            }],
+            method: Component::new("first"),
+        };
+        let election = registry.election(&file).unwrap();
+        assert_eq!(
+            election.run_once(0),
+            AnyOutcome::SingleWinner(SingleWinner::win(election.candidates(), 0))
+        );
+    }
+
+    #[derive(Debug)]
+    struct Sincere;
+
+    impl Tactic<Ordinal> for Sincere {
+        fn apply(&self, ballot: Ordinal) -> Ordinal {
+            ballot
+        }
+    }
+
+    #[test]
+    fn election_json_round_trip() {
+        let candidates = vec![
+            Candidate::new(0, "A", Some("Left"), None),
+            Candidate::new(1, "B", None, Some(vec![0.5])),
+            Candidate::new(2, "C", Some("Right"), None),
+        ];
+        let voting_blocs = vec![
+            VotingBloc::builder(preferences::Mallows::new(vec![0, 1, 2], 0.5), 30)
+                .add_tactic(tactics::Identity, 0.5)
+                .add_tactic(tactics::Burial::new(vec![2]), 0.5)
+                .build(),
+            VotingBloc::builder(preferences::Impartial, 20).build(),
+        ];
+        let method = methods::IRV.break_ties(TieBreaker::Backwards);
+        let election = Election::new(candidates, voting_blocs, method).unwrap();
+        let json = election.to_json().unwrap();
+        let read: Election<Ordinal, AnyMethod<Ordinal>> = Election::from_json(&json).unwrap();
+        assert_eq!(read.candidates(), election.candidates());
+        assert_eq!(read.candidates()[0].party(), Some("Left"));
+        assert_eq!(read.voting_blocs().len(), 2);
+        assert_eq!(read.voting_blocs()[0].strategy().len(), 2);
+        assert_eq!(read.to_json().unwrap(), json);
+        let outcome = AnyOutcome::from(election.run_once(7));
+        assert_eq!(read.run_once(7), outcome);
+
+        // The JSON is an election config file like any other.
+        let file = ElectionFile::from_json(&json).unwrap();
+        assert_eq!(file.ballot.as_deref(), Some("ordinal"));
+        assert_eq!(
+            file.method,
+            Component::new("irv").param("tie_breaker", "backwards")
+        );
+        assert_eq!(file.build().unwrap().run_once(7), outcome);
+
+        // Tactics that are not registered cannot be written to a config.
+        let candidates = vec![Candidate::new(0, "A", None, None)];
+        let voting_blocs = vec![
+            VotingBloc::builder(preferences::Impartial, 10)
+                .add_tactic(Sincere, 1.0)
+                .build(),
+        ];
+        let election = Election::new(candidates, voting_blocs, methods::IRV).unwrap();
+        assert!(matches!(
+            election.to_json(),
+            Err(ConfigError::Unregistered { kind: "tactic", .. })
+        ));
+    }
+
+    #[test]
+    fn election_json_errors() {
+        let json = r#"{
+            "candidates": [{"name": "A"}],
+            "voting_blocs": [{"members": 10, "preference": {"name": "impartial"}, "tactics": [{"name": "burial", "candidates": [0]}]}],
+            "method": {"name": "approval"}
+        }"#;
+        let read: Result<Election<Nominal, AnyMethod<Nominal>>, _> = Election::from_json(json);
+        assert!(matches!(
+            read,
+            Err(ConfigError::UnsupportedBallot {
+                kind: "tactic",
+                ballot: "Nominal",
+                ..
+            })
+        ));
+        let read: Result<Election<Ordinal, AnyMethod<Ordinal>>, _> =
+            Election::from_json(&json.replacen('{', r#"{"ballot": "nominal","#, 1));
+        assert!(matches!(
+            read,
+            Err(ConfigError::MismatchedBallot {
+                expected: "Ordinal",
+                ..
+            })
+        ));
+        let read: Result<Election<Nominal, AnyMethod<Nominal>>, _> = Election::from_json("{");
+        assert!(matches!(read, Err(ConfigError::Json(_))));
+    }
+
+    #[test]
+    fn method_descriptions() {
+        fn components(values: Value) -> Vec<Component> {
+            serde_json::from_value(values).unwrap()
+        }
+        fn assert_described<B: Ballot>(registry: &Registry, components: &[Component]) {
+            for component in components {
+                let method: AnyMethod<B> = registry.method(component).unwrap();
+                assert_eq!(&method.describe(), component);
+            }
+        }
+        let registry = Registry::default();
+        let ordinal = components(serde_json::json!([
+            { "name": "plurality" },
+            { "name": "borda" },
+            { "name": "irv", "tie_breaker": { "order": [1, 0] } },
+            { "name": "baldwin" },
+            { "name": "nanson" },
+            { "name": "coombs" },
+            { "name": "schulze" },
+            { "name": "bottom_two_runoff" },
+            { "name": "tideman_alternative" },
+            { "name": "two_round_runoff" },
+            { "name": "supplementary_vote" },
+            { "name": "bucklin", "variant": "tie" },
+            { "name": "minimax", "variant": "margins" },
+            { "name": "copeland", "tie_score": 0.5 },
+            { "name": "ranked_pairs", "strength": "winning_votes", "tie_breaker": { "order": [2, 0, 1] } },
+            { "name": "positional", "scores": [2.0, 1.0, 0.0] },
+            { "name": "kemeny", "limit": 8 },
+            { "name": "dodgson", "limit": 5 },
+            { "name": "young", "limit": 5 },
+            { "name": "smith", "method": { "name": "irv" } },
+            { "name": "schwartz", "method": { "name": "minimax", "variant": "winning_votes" } },
+            { "name": "stv", "seats": 2, "rule": "meek", "quota": "hare" },
+            { "name": "party_list", "seats": 3, "apportionment": "dhondt" },
+            { "name": "party_list", "seats": 3, "apportionment": "largest_remainder", "quota": "droop" },
+            { "name": "chamberlin_courant", "seats": 2, "solver": "approximate" },
+            { "name": "monroe", "seats": 2, "solver": "exact" },
+            { "name": "limited_vote", "seats": 3, "votes": 2 },
+            { "name": "sntv", "seats": 2 },
+            { "name": "block", "seats": 2 },
+            { "name": "random_dictator" },
+            { "name": "maximal_lottery" },
+        ]));
+        let nominal = components(serde_json::json!([
+            { "name": "approval" },
+            { "name": "phragmen", "seats": 2 },
+            { "name": "pav", "seats": 2, "solver": "exact" },
+            { "name": "sequential_pav", "seats": 2 },
+            { "name": "equal_shares", "seats": 2, "completion": "phragmen" },
+            { "name": "greedy_budgeting", "costs": [1.0, 2.0], "budget": 2.0 },
+            { "name": "equal_shares_budgeting", "costs": [1.0, 2.0], "budget": 2.0, "completion": false },
+            { "name": "phragmen_budgeting", "costs": [1.0, 2.0], "budget": 2.0 },
+            { "name": "proportional_lottery" },
+            { "name": "block", "seats": 3 },
+        ]));
+        let cardinal = components(serde_json::json!([
+            { "name": "score" },
+            { "name": "score", "quorum": 2 },
+            { "name": "star" },
+            { "name": "majority_judgment" },
+            { "name": "cumulative", "budget": 5 },
+            { "name": "cumulative", "budget": 5, "seats": 2 },
+            { "name": "quadratic", "budget": 9, "seats": 2 },
+            { "name": "equal_shares", "seats": 2, "completion": "none" },
+        ]));
+        assert_described::<Ordinal>(&registry, &ordinal);
+        assert_described::<Nominal>(&registry, &nominal);
+        assert_described::<Cardinal>(&registry, &cardinal);
+
+        // Every registered method can be written back to a config.
+        let described: Vec<Component> = [ordinal, nominal, cardinal].concat();
+        assert!(
+            registry
+                .methods()
+                .all(|name| described.iter().any(|component| component.name == name))
+        );
+    }
+}
@@ -0,0 +1,869 @@
+use std::any::{Any, TypeId};
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+use crate::config::{
+    AnyElection, AnyMethod, AnyOutcome, BlocSpec, CandidateSpec, Component, ConfigError, Describe,
+    ElectionFile, WeightedComponent,
+};
+use crate::core::{
+    Ballot, Cardinal, Election, Id, Method, MultiWinner, Nominal, Ordinal, Preference, Profile,
+    SingleWinner, Tactic, TieBreaker, VotingBloc, VotingBlocBuilder,
+};
+use crate::methods::{
+    Apportionment, Approval, Baldwin, Borda, BottomTwoRunoff, Bucklin, ChamberlinCourant,
+    Completion, Coombs, Copeland, Cumulative, Dodgson, EqualShares, EqualSharesBudgeting,
+    GreedyBudgeting, IRV, Kemeny, LimitedVote, MajorityJudgment, MaximalLottery, Minimax, Monroe,
+    Nanson, PAV, PartyList, Phragmen, PhragmenBudgeting, Plurality, Positional,
+    ProportionalLottery, Quadratic, Quota, RandomDictator, RankedPairs, STV, Schulze, Score,
+    SequentialPAV, SmithRestricted, Solver, Star, Strength, SupplementaryVote, TidemanAlternative,
+    TransferRule, TwoRoundRunoff, Young,
+};
+use crate::preferences::{Impartial, Mallows, Manual, PlackettLuce};
+use crate::tactics::{Bullet, Burial, Compromise, Identity, Pushover};
+
+/// A function that builds a component from its parameters.
+type Constructor<T> = Arc<dyn Fn(&Params) -> Result<T, ConfigError> + Send + Sync>;
+
+/// A function that writes a component back to its config, if it is of the type it was registered for.
+type Describer = Box<dyn Fn(&dyn Any) -> Option<Component> + Send + Sync>;
+
+/// A registered name, with its parameters and a constructor for each ballot type it supports.
+struct Entry {
+    params: &'static [&'static str],
+    constructors: BTreeMap<&'static str, Box<dyn Any + Send + Sync>>,
+}
+
+/// The parameters of a component in a config file, e.g. the `seats` of a method.
+pub struct Params<'a> {
+    registry: &'a Registry,
+    kind: &'static str,
+    name: &'a str,
+    values: &'a Map<String, Value>,
+    /// The number of candidates, when building a voting bloc.
+    candidates: Option<usize>,
+}
+
+impl Params<'_> {
+    /// Reads a required parameter.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the parameter is missing or has the wrong type.
+    pub fn get<T: DeserializeOwned>(&self, key: &'static str) -> Result<T, ConfigError> {
+        self.get_opt(key)?
+            .ok_or_else(|| ConfigError::MissingParameter {
+                kind: self.kind,
+                name: self.name.to_string(),
+                parameter: key,
+            })
+    }
+    /// Reads an optional parameter.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the parameter has the wrong type.
+    pub fn get_opt<T: DeserializeOwned>(
+        &self,
+        key: &'static str,
+    ) -> Result<Option<T>, ConfigError> {
+        self.values
+            .get(key)
+            .map(|value| T::deserialize(value).map_err(|e| self.invalid(key, e.to_string())))
+            .transpose()
+    }
+    /// Reads an optional parameter, falling back to a default.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the parameter has the wrong type.
+    pub fn get_or<T: DeserializeOwned>(
+        &self,
+        key: &'static str,
+        default: T,
+    ) -> Result<T, ConfigError> {
+        Ok(self.get_opt(key)?.unwrap_or(default))
+    }
+    /// Reads a parameter that names one of the given options, falling back to the first option.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the parameter is not the name of an option.
+    pub fn choice<T: Clone>(
+        &self,
+        key: &'static str,
+        options: &[(&'static str, T)],
+    ) -> Result<T, ConfigError> {
+        let Some(choice) = self.get_opt::<String>(key)? else {
+            return Ok(options[0].1.clone());
+        };
+        options
+            .iter()
+            .find(|(name, _)| *name == choice)
+            .map(|(_, option)| option.clone())
+            .ok_or_else(|| {
+                let names: Vec<&str> = options.iter().map(|(name, _)| *name).collect();
+                self.invalid(key, format!("expected one of: {}", names.join(", ")))
+            })
+    }
+    /// Reads a required parameter listing candidate ids.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the parameter is missing, has the wrong type, or names an unknown candidate.
+    pub fn candidates(&self, key: &'static str) -> Result<Vec<Id>, ConfigError> {
+        let ids: Vec<Id> = self.get(key)?;
+        self.check_candidates(key, ids.iter().copied())?;
+        Ok(ids)
+    }
+    /// Reads a required parameter with one entry per candidate.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the parameter is missing, has the wrong type, or has the wrong number of entries.
+    pub fn per_candidate<T: DeserializeOwned>(
+        &self,
+        key: &'static str,
+    ) -> Result<Vec<T>, ConfigError> {
+        let entries: Vec<T> = self.get(key)?;
+        match self.candidates {
+            Some(n) if entries.len() != n => Err(self.invalid(
+                key,
+                format!(
+                    "expected one entry per candidate ({n}), found {}",
+                    entries.len()
+                ),
+            )),
+            _ => Ok(entries),
+        }
+    }
+    /// Checks that every id given in a parameter is the id of a candidate.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an id is not less than the number of candidates.
+    pub fn check_candidates(
+        &self,
+        key: &'static str,
+        ids: impl IntoIterator<Item = Id>,
+    ) -> Result<(), ConfigError> {
+        let Some(n) = self.candidates else {
+            return Ok(());
+        };
+        ids.into_iter().find(|&id| id >= n).map_or(Ok(()), |id| {
+            Err(self.invalid(
+                key,
+                format!("unknown candidate {id} (there are {n} candidates)"),
+            ))
+        })
+    }
+    /// Builds a method given as a parameter, e.g. the method restricted to the Smith set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the parameter is missing or is not a valid method.
+    pub fn method<B: Ballot>(&self, key: &'static str) -> Result<AnyMethod<B>, ConfigError> {
+        let component: Component = self.get(key)?;
+        self.registry.method(&component)
+    }
+    fn invalid(&self, key: &'static str, message: String) -> ConfigError {
+        ConfigError::InvalidParameter {
+            kind: self.kind,
+            name: self.name.to_string(),
+            parameter: key,
+            message,
+        }
+    }
+}
+
+/// A registry of methods, preferences and tactics by name, used to build elections from config files.
+///
+/// The default registry contains every built-in method, preference and tactic, under snake case names (e.g. `"irv"`, `"mallows"` and `"burial"`). Custom components can be registered alongside them.
+///
+/// Every method also accepts a `tie_breaker` parameter, e.g. `"backwards"` or `{ order = [2, 0, 1] }` (see [`TieBreaker`]).
+pub struct Registry {
+    methods: BTreeMap<&'static str, Entry>,
+    preferences: BTreeMap<&'static str, Entry>,
+    tactics: BTreeMap<&'static str, Entry>,
+    describers: BTreeMap<TypeId, Describer>,
+}
+
+impl Registry {
+    /// Creates an empty registry.
+    #[must_use]
+    pub const fn empty() -> Self {
+        Self {
+            methods: BTreeMap::new(),
+            preferences: BTreeMap::new(),
+            tactics: BTreeMap::new(),
+            describers: BTreeMap::new(),
+        }
+    }
+    /// Registers a method under a name, with the parameters it accepts.
+    ///
+    /// A name can be registered once for each ballot type, e.g. limited voting accepts both ranked and approval ballots.
+    pub fn register_method<M, F>(
+        &mut self,
+        name: &'static str,
+        params: &'static [&'static str],
+        constructor: F,
+    ) -> &mut Self
+    where
+        M: Method + Describe + 'static,
+        M::Winner: Into<AnyOutcome>,
+        F: Fn(&Params) -> Result<M, ConfigError> + Send + Sync + 'static,
+    {
+        let constructor: Constructor<AnyMethod<M::Ballot>> =
+            Arc::new(move |params| constructor(params).map(AnyMethod::new));
+        insert(
+            &mut self.methods,
+            name,
+            params,
+            M::Ballot::NAME,
+            constructor,
+        );
+        self
+    }
+    /// Registers a preference model for a ballot type under a name, with the parameters it accepts.
+    pub fn register_preference<B, P, F>(
+        &mut self,
+        name: &'static str,
+        params: &'static [&'static str],
+        constructor: F,
+    ) -> &mut Self
+    where
+        B: Ballot,
+        P: Preference<B> + Describe,
+        F: Fn(&Params) -> Result<P, ConfigError> + Send + Sync + 'static,
+    {
+        self.describers.insert(TypeId::of::<P>(), describer::<P>());
+        let constructor: Constructor<Arc<dyn Preference<B>>> =
+            Arc::new(move |params| Ok(Arc::new(constructor(params)?)));
+        insert(&mut self.preferences, name, params, B::NAME, constructor);
+        self
+    }
+    /// Registers a tactic for a ballot type under a name, with the parameters it accepts.
+    pub fn register_tactic<B, T, F>(
+        &mut self,
+        name: &'static str,
+        params: &'static [&'static str],
+        constructor: F,
+    ) -> &mut Self
+    where
+        B: Ballot,
+        T: Tactic<B> + Describe,
+        F: Fn(&Params) -> Result<T, ConfigError> + Send + Sync + 'static,
+    {
+        self.describers.insert(TypeId::of::<T>(), describer::<T>());
+        let constructor: Constructor<Arc<dyn Tactic<B>>> =
+            Arc::new(move |params| Ok(Arc::new(constructor(params)?)));
+        insert(&mut self.tactics, name, params, B::NAME, constructor);
+        self
+    }
+    /// The names of the registered methods.
+    pub fn methods(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.methods.keys().copied()
+    }
+    /// The names of the registered preference models.
+    pub fn preferences(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.preferences.keys().copied()
+    }
+    /// The names of the registered tactics.
+    pub fn tactics(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.tactics.keys().copied()
+    }
+    /// Builds a method from its config.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the method is unknown, does not support the ballot type, or its parameters are invalid.
+    pub fn method<B: Ballot>(&self, component: &Component) -> Result<AnyMethod<B>, ConfigError> {
+        let mut component = component.clone();
+        let Some(tie_breaker) = component.params.remove("tie_breaker") else {
+            return self.construct(&self.methods, "method", &component, B::NAME, None);
+        };
+        let tie_breaker =
+            TieBreaker::deserialize(tie_breaker).map_err(|e| ConfigError::InvalidParameter {
+                kind: "method",
+                name: component.name.clone(),
+                parameter: "tie_breaker",
+                message: e.to_string(),
+            })?;
+        let method: AnyMethod<B> =
+            self.construct(&self.methods, "method", &component, B::NAME, None)?;
+        Ok(AnyMethod::new(method.break_ties(tie_breaker)))
+    }
+    /// Builds a preference model for the given number of candidates from its config.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the preference model is unknown, does not support the ballot type, or its parameters are invalid (including candidate ids out of range).
+    pub fn preference<B: Ballot>(
+        &self,
+        component: &Component,
+        candidates: usize,
+    ) -> Result<Arc<dyn Preference<B>>, ConfigError> {
+        self.construct(
+            &self.preferences,
+            "preference",
+            component,
+            B::NAME,
+            Some(candidates),
+        )
+    }
+    /// Builds a tactic for the given number of candidates from its config.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the tactic is unknown, does not support the ballot type, or its parameters are invalid (including candidate ids out of range).
+    pub fn tactic<B: Ballot>(
+        &self,
+        component: &Component,
+        candidates: usize,
+    ) -> Result<Arc<dyn Tactic<B>>, ConfigError> {
+        self.construct(
+            &self.tactics,
+            "tactic",
+            component,
+            B::NAME,
+            Some(candidates),
+        )
+    }
+    /// Builds a voting bloc for the given number of candidates from its config.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if its preference model or any of its tactics cannot be built.
+    pub fn voting_bloc<B: Ballot>(
+        &self,
+        bloc: &BlocSpec,
+        candidates: usize,
+    ) -> Result<VotingBloc<B>, ConfigError> {
+        let mut builder =
+            VotingBlocBuilder::shared(self.preference(&bloc.preference, candidates)?, bloc.members);
+        for tactic in &bloc.tactics {
+            builder = builder
+                .add_shared_tactic(self.tactic(&tactic.component, candidates)?, tactic.weight);
+        }
+        Ok(builder.build())
+    }
+    /// Writes a voting bloc back to its config.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if its preference model or any of its tactics is not of a registered type.
+    pub fn describe_bloc<B: Ballot>(&self, bloc: &VotingBloc<B>) -> Result<BlocSpec, ConfigError> {
+        let preference = bloc.preferences();
+        let tactics = bloc
+            .strategy()
+            .iter()
+            .map(|(tactic, weight)| {
+                Ok(WeightedComponent {
+                    weight: *weight,
+                    component: self.describe("tactic", tactic.as_ref(), tactic.as_ref())?,
+                })
+            })
+            .collect::<Result<_, ConfigError>>()?;
+        Ok(BlocSpec {
+            members: bloc.members(),
+            preference: self.describe("preference", preference.as_ref(), preference.as_ref())?,
+            tactics,
+        })
+    }
+    /// Writes an election back to its config file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a voting bloc uses a preference model or tactic that is not of a registered type.
+    pub fn describe_election<B, M>(
+        &self,
+        election: &Election<B, M>,
+    ) -> Result<ElectionFile, ConfigError>
+    where
+        B: Ballot,
+        M: Method<Ballot = B> + Describe,
+    {
+        Ok(ElectionFile {
+            ballot: Some(B::NAME.to_lowercase()),
+            candidates: election
+                .candidates()
+                .iter()
+                .map(CandidateSpec::from)
+                .collect(),
+            voting_blocs: election
+                .voting_blocs()
+                .iter()
+                .map(|bloc| self.describe_bloc(bloc))
+                .collect::<Result<_, _>>()?,
+            method: election.method().describe(),
+        })
+    }
+    /// Builds an election from its config file.
+    ///
+    /// If the config file does not give the ballot type, it is inferred from the method, which must then support only one ballot type.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ballot type is unknown or ambiguous, any component cannot be built, or the election is invalid.
+    pub fn election(&self, file: &ElectionFile) -> Result<AnyElection, ConfigError> {
+        let ballot = match &file.ballot {
+            Some(ballot) => [Nominal::NAME, Ordinal::NAME, Cardinal::NAME]
+                .into_iter()
+                .find(|name| name.eq_ignore_ascii_case(ballot))
+                .ok_or_else(|| ConfigError::UnknownBallot(ballot.clone()))?,
+            None => self.ballot(&file.method)?,
+        };
+        Ok(match ballot {
+            Nominal::NAME => AnyElection::Nominal(self.typed_election(file)?),
+            Ordinal::NAME => AnyElection::Ordinal(self.typed_election(file)?),
+            _ => AnyElection::Cardinal(self.typed_election(file)?),
+        })
+    }
+    /// Infers the ballot type of a method.
+    fn ballot(&self, component: &Component) -> Result<&'static str, ConfigError> {
+        let entry = lookup(&self.methods, "method", &component.name)?;
+        let ballots: Vec<&'static str> = entry.constructors.keys().copied().collect();
+        match ballots[..] {
+            [ballot] => Ok(ballot),
+            _ => Err(ConfigError::AmbiguousBallot {
+                name: component.name.clone(),
+                ballots: ballots.join(", "),
+            }),
+        }
+    }
+    pub(super) fn typed_election<B: Ballot>(
+        &self,
+        file: &ElectionFile,
+    ) -> Result<Election<B, AnyMethod<B>>, ConfigError> {
+        let candidates = file
+            .candidates
+            .iter()
+            .enumerate()
+            .map(|(id, candidate)| candidate.candidate(id))
+            .collect::<Result<Vec<_>, _>>()?;
+        let voting_blocs = file
+            .voting_blocs
+            .iter()
+            .map(|bloc| self.voting_bloc(bloc, candidates.len()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let method = self.method(&file.method)?;
+        Ok(Election::new(candidates, voting_blocs, method)?)
+    }
+    fn describe(
+        &self,
+        kind: &'static str,
+        component: &dyn Any,
+        debug: &dyn Debug,
+    ) -> Result<Component, ConfigError> {
+        self.describers
+            .get(&component.type_id())
+            .and_then(|describer| describer(component))
+            .ok_or_else(|| ConfigError::Unregistered {
+                kind,
+                name: format!("{debug:?}"),
+            })
+    }
+    fn construct<T: 'static>(
+        &self,
+        entries: &BTreeMap<&'static str, Entry>,
+        kind: &'static str,
+        component: &Component,
+        ballot: &'static str,
+        candidates: Option<usize>,
+    ) -> Result<T, ConfigError> {
+        let entry = lookup(entries, kind, &component.name)?;
+        if let Some(parameter) = component
+            .params
+            .keys()
+            .find(|key| !entry.params.contains(&key.as_str()))
+        {
+            return Err(ConfigError::UnknownParameter {
+                kind,
+                name: component.name.clone(),
+                parameter: parameter.clone(),
+                expected: match entry.params {
+                    [] => "none".to_string(),
+                    params => params.join(", "),
+                },
+            });
+        }
+        let constructor = entry
+            .constructors
+            .get(ballot)
+            .and_then(|constructor| constructor.downcast_ref::<Constructor<T>>())
+            .ok_or_else(|| ConfigError::UnsupportedBallot {
+                kind,
+                name: component.name.clone(),
+                ballot,
+            })?;
+        constructor(&Params {
+            registry: self,
+            kind,
+            name: &component.name,
+            values: &component.params,
+            candidates,
+        })
+    }
+}
+
+fn describer<T: Describe + 'static>() -> Describer {
+    Box::new(|component| component.downcast_ref::<T>().map(Describe::describe))
+}
+
+fn insert<T: Send + Sync + 'static>(
+    entries: &mut BTreeMap<&'static str, Entry>,
+    name: &'static str,
+    params: &'static [&'static str],
+    ballot: &'static str,
+    constructor: Constructor<T>,
+) {
+    let entry = entries.entry(name).or_insert_with(|| Entry {
+        params,
+        constructors: BTreeMap::new(),
+    });
+    entry.params = params;
+    entry.constructors.insert(ballot, Box::new(constructor));
+}
+
+fn lookup<'a>(
+    entries: &'a BTreeMap<&'static str, Entry>,
+    kind: &'static str,
+    name: &str,
+) -> Result<&'a Entry, ConfigError> {
+    entries.get(name).ok_or_else(|| ConfigError::Unknown {
+        kind,
+        name: name.to_string(),
+        known: entries.keys().copied().collect::<Vec<_>>().join(", "),
+    })
+}
+
+/// The seats and votes of limited voting, where voters must have fewer votes than there are seats.
+fn limited_votes(p: &Params) -> Result<(usize, usize), ConfigError> {
+    let seats: usize = p.get("seats")?;
+    let votes: usize = p.get("votes")?;
+    if votes == 0 || votes >= seats {
+        return Err(p.invalid(
+            "votes",
+            format!(
+                "expected between 1 and {} votes (use `block` for as many votes as seats)",
+                seats.saturating_sub(1)
+            ),
+        ));
+    }
+    Ok((seats, votes))
+}
+
+const SOLVERS: [(&str, Solver); 2] = [
+    ("exact", Solver::Exact),
+    ("approximate", Solver::Approximate),
+];
+const QUOTAS: [(&str, Quota); 2] = [("droop", Quota::Droop), ("hare", Quota::Hare)];
+
+impl Default for Registry {
+    /// Creates a registry of every built-in method, preference model and tactic.
+    #[allow(clippy::too_many_lines)]
+    fn default() -> Self {
+        let mut registry = Self::empty();
+
+        // Single-winner ranked methods
+        registry
+            .register_method("plurality", &[], |_| Ok(Plurality))
+            .register_method("borda", &[], |_| Ok(Borda))
+            .register_method("irv", &[], |_| Ok(IRV))
+            .register_method("baldwin", &[], |_| Ok(Baldwin))
+            .register_method("nanson", &[], |_| Ok(Nanson))
+            .register_method("coombs", &[], |_| Ok(Coombs))
+            .register_method("schulze", &[], |_| Ok(Schulze))
+            .register_method("bottom_two_runoff", &[], |_| Ok(BottomTwoRunoff))
+            .register_method("tideman_alternative", &[], |_| Ok(TidemanAlternative))
+            .register_method("two_round_runoff", &[], |_| Ok(TwoRoundRunoff))
+            .register_method("supplementary_vote", &[], |_| Ok(SupplementaryVote))
+            .register_method("bucklin", &["variant"], |p| {
+                p.choice(
+                    "variant",
+                    &[("most_votes", Bucklin::MostVotes), ("tie", Bucklin::Tie)],
+                )
+            })
+            .register_method("minimax", &["variant"], |p| {
+                p.choice(
+                    "variant",
+                    &[
+                        ("winning_votes", Minimax::WinningVotes),
+                        ("margins", Minimax::Margins),
+                        ("pairwise_opposition", Minimax::PairwiseOpposition),
+                    ],
+                )
+            })
+            .register_method("copeland", &["tie_score"], |p| {
+                Ok(Copeland::new(p.get_or("tie_score", 0.5)?))
+            })
+            .register_method("ranked_pairs", &["strength"], |p| {
+                Ok(RankedPairs::new(p.choice(
+                    "strength",
+                    &[
+                        ("margins", Strength::Margins),
+                        ("winning_votes", Strength::WinningVotes),
+                    ],
+                )?))
+            })
+            .register_method("positional", &["scores"], |p| {
+                Ok(Positional::new(p.get("scores")?))
+            })
+            // past `limit` candidates, Kemeny approximates the consensus by local search
+            .register_method("kemeny", &["limit"], |p| {
+                let limit = p.get_or("limit", 10)?;
+                if limit > Kemeny::MAX_LIMIT {
+                    return Err(p.invalid(
+                        "limit",
+                        format!("expected at most {} candidates", Kemeny::MAX_LIMIT),
+                    ));
+                }
+                Ok(Kemeny::new(limit))
+            })
+            .register_method("dodgson", &["limit"], |p| {
+                Ok(Dodgson::new(p.get_or("limit", 6)?))
+            })
+            .register_method("young", &["limit"], |p| {
+                Ok(Young::new(p.get_or("limit", 6)?))
+            })
+            .register_method("smith", &["method"], |p| {
+                Ok(SmithRestricted::new(p.method("method")?))
+            })
+            .register_method("schwartz", &["method"], |p| {
+                Ok(SmithRestricted::schwartz(p.method::<Ordinal>("method")?))
+            });
+
+        // Multi-winner ranked methods
+        registry
+            .register_method("stv", &["seats", "rule", "quota"], |p| {
+                let rule = p.choice(
+                    "rule",
+                    &[
+                        ("wig", TransferRule::WeightedInclusiveGregory),
+                        ("scottish", TransferRule::Scottish),
+                        ("meek", TransferRule::Meek),
+                    ],
+                )?;
+                Ok(STV::with_rule(p.get("seats")?, rule).quota(p.choice("quota", &QUOTAS)?))
+            })
+            .register_method("party_list", &["seats", "apportionment", "quota"], |p| {
+                let quota = p.choice("quota", &[("hare", Quota::Hare), ("droop", Quota::Droop)])?;
+                let apportionment = p.choice(
+                    "apportionment",
+                    &[
+                        ("dhondt", Apportionment::DHondt),
+                        ("sainte_lague", Apportionment::SainteLague),
+                        ("modified_sainte_lague", Apportionment::ModifiedSainteLague),
+                        ("largest_remainder", Apportionment::LargestRemainder(quota)),
+                    ],
+                )?;
+                if !matches!(apportionment, Apportionment::LargestRemainder(_))
+                    && p.get_opt::<Value>("quota")?.is_some()
+                {
+                    return Err(p.invalid(
+                        "quota",
+                        "only the largest_remainder apportionment takes a quota".to_string(),
+                    ));
+                }
+                Ok(PartyList::new(p.get("seats")?, apportionment))
+            })
+            .register_method("chamberlin_courant", &["seats", "solver"], |p| {
+                Ok(ChamberlinCourant::new(
+                    p.get("seats")?,
+                    p.choice("solver", &SOLVERS)?,
+                ))
+            })
+            .register_method("monroe", &["seats", "solver"], |p| {
+                Ok(Monroe::new(p.get("seats")?, p.choice("solver", &SOLVERS)?))
+            });
+
+        // Limited voting, for ranked or approval ballots
+        registry
+            .register_method("limited_vote", &["seats", "votes"], |p| {
+                let (seats, votes) = limited_votes(p)?;
+                Ok(LimitedVote::<Ordinal>::new(seats, votes))
+            })
+            .register_method("limited_vote", &["seats", "votes"], |p| {
+                let (seats, votes) = limited_votes(p)?;
+                Ok(LimitedVote::<Nominal>::new(seats, votes))
+            })
+            .register_method("sntv", &["seats"], |p| {
+                Ok(LimitedVote::<Ordinal>::sntv(p.get("seats")?))
+            })
+            .register_method("sntv", &["seats"], |p| {
+                Ok(LimitedVote::<Nominal>::sntv(p.get("seats")?))
+            })
+            .register_method("block", &["seats"], |p| {
+                Ok(LimitedVote::<Ordinal>::block(p.get("seats")?))
+            })
+            .register_method("block", &["seats"], |p| {
+                Ok(LimitedVote::<Nominal>::block(p.get("seats")?))
+            });
+
+        // Approval methods
+        registry
+            .register_method("approval", &[], |_| Ok(Approval))
+            .register_method("phragmen", &["seats"], |p| {
+                Ok(Phragmen::new(p.get("seats")?))
+            })
+            .register_method("pav", &["seats", "solver"], |p| {
+                Ok(PAV::new(p.get("seats")?, p.choice("solver", &SOLVERS)?))
+            })
+            .register_method("sequential_pav", &["seats"], |p| {
+                Ok(SequentialPAV::new(p.get("seats")?))
+            });
+
+        // Score methods
+        registry
+            .register_method("score", &["quorum"], |p| {
+                Ok(p.get_opt("quorum")?
+                    .map_or(Score::Total, |quorum| Score::Average { quorum }))
+            })
+            .register_method("star", &[], |_| Ok(Star))
+            .register_method("majority_judgment", &[], |_| Ok(MajorityJudgment))
+            .register_method("cumulative", &["budget", "seats"], |p| {
+                let budget = p.get("budget")?;
+                Ok(p.get_opt("seats")?.map_or_else(
+                    || AnyMethod::new(Cumulative::<SingleWinner>::new(budget)),
+                    |seats| AnyMethod::new(Cumulative::<MultiWinner>::new(seats, budget)),
+                ))
+            })
+            .register_method("quadratic", &["budget", "seats"], |p| {
+                let budget = p.get("budget")?;
+                Ok(p.get_opt("seats")?.map_or_else(
+                    || AnyMethod::new(Quadratic::<SingleWinner>::new(budget)),
+                    |seats| AnyMethod::new(Quadratic::<MultiWinner>::new(seats, budget)),
+                ))
+            });
+
+        // Methods for approval or score ballots
+        let completions = [
+            ("none", Completion::None),
+            ("utilitarian", Completion::Utilitarian),
+            ("phragmen", Completion::Phragmen),
+            ("add_one", Completion::AddOne),
+        ];
+        registry
+            .register_method("equal_shares", &["seats", "completion"], move |p| {
+                Ok(EqualShares::<Nominal>::new(
+                    p.get("seats")?,
+                    p.choice("completion", &completions)?,
+                ))
+            })
+            .register_method("equal_shares", &["seats", "completion"], move |p| {
+                Ok(EqualShares::<Cardinal>::new(
+                    p.get("seats")?,
+                    p.choice("completion", &completions)?,
+                ))
+            })
+            .register_method("greedy_budgeting", &["costs", "budget"], |p| {
+                Ok(GreedyBudgeting::<Nominal>::new(
+                    p.get("costs")?,
+                    p.get("budget")?,
+                ))
+            })
+            .register_method("greedy_budgeting", &["costs", "budget"], |p| {
+                Ok(GreedyBudgeting::<Cardinal>::new(
+                    p.get("costs")?,
+                    p.get("budget")?,
+                ))
+            })
+            .register_method(
+                "equal_shares_budgeting",
+                &["costs", "budget", "completion"],
+                equal_shares_budgeting::<Nominal>,
+            )
+            .register_method(
+                "equal_shares_budgeting",
+                &["costs", "budget", "completion"],
+                equal_shares_budgeting::<Cardinal>,
+            )
+            .register_method("phragmen_budgeting", &["costs", "budget"], |p| {
+                Ok(PhragmenBudgeting::new(p.get("costs")?, p.get("budget")?))
+            });
+
+        // Lotteries
+        registry
+            .register_method("random_dictator", &[], |_| Ok(RandomDictator))
+            .register_method("maximal_lottery", &[], |_| Ok(MaximalLottery))
+            .register_method("proportional_lottery", &[], |_| Ok(ProportionalLottery));
+
+        // Preferences
+        registry
+            .register_preference::<Nominal, _, _>("impartial", &[], |_| Ok(Impartial))
+            .register_preference::<Ordinal, _, _>("impartial", &[], |_| Ok(Impartial))
+            .register_preference::<Cardinal, _, _>("impartial", &[], |_| Ok(Impartial))
+            .register_preference("mallows", &["reference", "phi"], |p| {
+                let reference = p.per_candidate("reference")?;
+                p.check_candidates("reference", reference.iter().copied())?;
+                Ok(Mallows::new(reference, p.get("phi")?))
+            })
+            .register_preference("plackett_luce", &["weights"], |p| {
+                let weights: Vec<(Id, f32)> = p.per_candidate("weights")?;
+                p.check_candidates("weights", weights.iter().map(|(id, _)| *id))?;
+                Ok(PlackettLuce::new(weights))
+            })
+            .register_preference("manual", &["ballots"], |p| {
+                let ballots: Profile<Nominal> = p.get("ballots")?;
+                p.check_candidates("ballots", ballots.iter().flat_map(|b| b.iter().copied()))?;
+                Ok(Manual::new(ballots))
+            })
+            .register_preference("manual", &["ballots"], |p| {
+                let ballots: Profile<Ordinal> = p.get("ballots")?;
+                p.check_candidates("ballots", ballots.iter().flat_map(|b| b.iter().copied()))?;
+                Ok(Manual::new(ballots))
+            })
+            .register_preference("manual", &["ballots"], |p| {
+                let ballots: Profile<Cardinal> = p.get("ballots")?;
+                p.check_candidates("ballots", ballots.iter().flat_map(|b| b.keys().copied()))?;
+                Ok(Manual::new(ballots))
+            });
+
+        // Tactics
+        registry
+            .register_tactic::<Nominal, _, _>("identity", &[], |_| Ok(Identity))
+            .register_tactic::<Ordinal, _, _>("identity", &[], |_| Ok(Identity))
+            .register_tactic::<Cardinal, _, _>("identity", &[], |_| Ok(Identity))
+            .register_tactic::<Nominal, _, _>("bullet", &["candidates"], |p| {
+                Ok(Bullet::new(p.candidates("candidates")?))
+            })
+            .register_tactic::<Ordinal, _, _>("bullet", &["candidates"], bullet)
+            .register_tactic::<Cardinal, _, _>("bullet", &["candidates"], bullet)
+            .register_tactic("burial", &["candidates"], |p| {
+                Ok(Burial::new(p.candidates("candidates")?))
+            })
+            .register_tactic("compromise", &["candidates"], |p| {
+                Ok(Compromise::new(p.candidates("candidates")?))
+            })
+            .register_tactic("pushover", &["preferred", "pushover"], |p| {
+                Ok(Pushover::new(
+                    p.candidates("preferred")?,
+                    p.candidates("pushover")?,
+                ))
+            });
+
+        registry
+    }
+}
+
+/// Bullet voting for the given candidates, or else for the favorite of each ballot.
+fn bullet(p: &Params) -> Result<Bullet, ConfigError> {
+    match p.get_opt::<Vec<Id>>("candidates")? {
+        Some(_) => Ok(Bullet::new(p.candidates("candidates")?)),
+        None => Ok(Bullet::default()),
+    }
+}
+
+fn equal_shares_budgeting<B: Ballot>(p: &Params) -> Result<EqualSharesBudgeting<B>, ConfigError> {
+    let method = EqualSharesBudgeting::new(p.get("costs")?, p.get("budget")?);
+    Ok(if p.get_or("completion", true)? {
+        method
+    } else {
+        method.without_completion()
+    })
+}
@@ -1,39 +1,83 @@
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Debug;
+use std::hash::Hash;
 use std::ops::Deref;
 use std::slice::Iter;
 
 use derive_more::{Deref, DerefMut, From};
-use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 
 use crate::core::Id;
 
 /// A ballot type that can be cast in an election
 ///
 /// A ballot is an expression of a voter's preferences. There are three ballot types: `Nominal`, `Ordinal`, and `Cardinal`.
-pub trait Ballot: Debug + Deref + Send + Sync + Clone + Serialize {}
+pub trait Ballot:
+    Debug + Deref + Send + Sync + Clone + Hash + Serialize + DeserializeOwned + 'static
+{
+    /// The name of the ballot type
+    const NAME: &'static str;
+    /// The Borda count this ballot gives each of `n` candidates (indexed by id): the number of candidates it ranks strictly below them. Candidates left off the ballot are ranked below every candidate on it.
+    fn borda(&self, n: usize) -> Vec<usize>;
+}
 
 /// Approval ballot: A set of approved candidates
 #[repr(transparent)]
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deref, DerefMut, Serialize)]
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deref, DerefMut, Serialize, Deserialize,
+)]
 pub struct Nominal(pub BTreeSet<Id>);
-impl Ballot for Nominal {}
+impl Ballot for Nominal {
+    const NAME: &'static str = "Nominal";
+    fn borda(&self, n: usize) -> Vec<usize> {
+        let mut borda = vec![0; n];
+        for &c in self.iter().filter(|&&c| c < n) {
+            borda[c] = n.saturating_sub(self.len());
+        }
+        borda
+    }
+}
 
 /// Ranked ballot: An ordered list of candidates
 #[repr(transparent)]
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deref, DerefMut, Serialize)]
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deref, DerefMut, Serialize, Deserialize,
+)]
 pub struct Ordinal(pub Vec<Id>);
-impl Ballot for Ordinal {}
+impl Ballot for Ordinal {
+    const NAME: &'static str = "Ordinal";
+    fn borda(&self, n: usize) -> Vec<usize> {
+        let mut borda = vec![0; n];
+        for (i, &c) in self.iter().enumerate().filter(|(_, c)| **c < n) {
+            borda[c] = n.saturating_sub(i + 1);
+        }
+        borda
+    }
+}
 
 /// Score ballot: A map of candidates to scores
 #[repr(transparent)]
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deref, DerefMut, Serialize)]
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deref, DerefMut, Serialize, Deserialize,
+)]
 pub struct Cardinal(pub BTreeMap<Id, usize>);
-impl Ballot for Cardinal {}
+impl Ballot for Cardinal {
+    const NAME: &'static str = "Cardinal";
+    fn borda(&self, n: usize) -> Vec<usize> {
+        let mut borda = vec![0; n];
+        for (&c, score) in self.iter().filter(|(c, _)| **c < n) {
+            let below = self.values().filter(|other| *other < score).count();
+            borda[c] = below + n.saturating_sub(self.len());
+        }
+        borda
+    }
+}
 
 /// A collection of ballots.
 #[repr(transparent)]
-#[derive(Debug, Clone, PartialEq, Eq, From, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, From, Serialize, Deserialize)]
+#[serde(bound = "")]
 pub struct Profile<B: Ballot>(Box<[B]>);
 
 impl<B: Ballot> Profile<B> {
@@ -1,21 +1,23 @@
+use std::sync::Arc;
+
 use ordered_float::NotNan;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::core::Id;
 
 /// A candidate in an election.
 ///
 /// Each candidate has an id, name, (optional) party, and (optional) list of positions.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct Candidate {
     /// Unique identifier for the candidate
     ///
     /// Exists to make inputting manual ballots easier (Id instead of a name)
     id: Id,
     /// The name of the candidate
-    name: &'static str,
+    name: Arc<str>,
     /// The party the candidate is associated with
-    party: Option<&'static str>,
+    party: Option<Arc<str>>,
     /// The positions the candidate holds
     positions: Option<Vec<NotNan<f32>>>,
 }
@@ -27,12 +29,7 @@ impl Candidate {
     ///
     /// If any position entry is NaN, then a valid position cannot be instantiated, and the function will panic.
     #[must_use]
-    pub fn new(
-        id: Id,
-        name: &'static str,
-        party: Option<&'static str>,
-        positions: Option<Vec<f32>>,
-    ) -> Self {
+    pub fn new(id: Id, name: &str, party: Option<&str>, positions: Option<Vec<f32>>) -> Self {
         let positions = positions.map(|positions| {
             positions
                 .into_iter()
@@ -41,8 +38,8 @@ impl Candidate {
         });
         Self {
             id,
-            name,
-            party,
+            name: name.into(),
+            party: party.map(Into::into),
             positions,
         }
     }
@@ -51,15 +48,20 @@ impl Candidate {
     pub const fn id(&self) -> Id {
         self.id
     }
+    /// The same candidate under another id
+    #[must_use]
+    pub(crate) fn with_id(&self, id: Id) -> Self {
+        Self { id, ..self.clone() }
+    }
     /// The name of the candidate
     #[must_use]
-    pub const fn name(&self) -> &str {
-        self.name
+    pub fn name(&self) -> &str {
+        &self.name
     }
     /// The party the candidate is associated with
     #[must_use]
-    pub const fn party(&self) -> Option<&str> {
-        self.party
+    pub fn party(&self) -> Option<&str> {
+        self.party.as_deref()
     }
     /// The positions that the candidate holds
     #[must_use]
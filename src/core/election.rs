@@ -7,17 +7,17 @@ use crate::core::{Ballot, Candidate, Method, Outcome, Profile, VotingBloc};
 
 /// An election is a simulation of the voting process. It is constructed with a set of conditions, a set of candidates, a set of voting blocs, and a method for determining the winner.
 #[derive(Debug)]
-pub struct Election<const N: usize, const V: usize, B, M>
+pub struct Election<B, M>
 where
     B: Ballot,
     M: Method<Ballot = B>,
 {
-    candidates: [Candidate; N],
-    voting_blocs: [VotingBloc<B>; V],
+    candidates: Vec<Candidate>,
+    voting_blocs: Vec<VotingBloc<B>>,
     method: M,
 }
 
-impl<const N: usize, const V: usize, B, M> Election<N, V, B, M>
+impl<B, M> Election<B, M>
 where
     B: Ballot,
     M: Method<Ballot = B>,
@@ -28,8 +28,8 @@ where
     ///
     /// Returns an error if the election configuration is invalid.
     fn validate(
-        candidates: &[Candidate; N],
-        _voting_blocs: &[VotingBloc<B>; V],
+        candidates: &[Candidate],
+        _voting_blocs: &[VotingBloc<B>],
         _method: &M,
     ) -> Result<(), ElectionError> {
         if candidates.iter().any(|c| c.id() >= candidates.len()) {
@@ -47,14 +47,17 @@ where
 
     /// Creates a new election configuration
     ///
+    /// The candidates and voting blocs can be given as arrays or vectors, so their number need not be known at compile time.
+    ///
     /// # Errors
     ///
     /// Returns an error if the election configuration is invalid.
     pub fn new(
-        candidates: [Candidate; N],
-        voting_blocs: [VotingBloc<B>; V],
+        candidates: impl Into<Vec<Candidate>>,
+        voting_blocs: impl Into<Vec<VotingBloc<B>>>,
         method: M,
     ) -> Result<Self, ElectionError> {
+        let (candidates, voting_blocs) = (candidates.into(), voting_blocs.into());
         Self::validate(&candidates, &voting_blocs, &method)?;
         Ok(Self {
             candidates,
@@ -63,11 +66,11 @@ where
         })
     }
     /// Get the candidates up for election
-    pub const fn candidates(&self) -> &[Candidate] {
+    pub fn candidates(&self) -> &[Candidate] {
         &self.candidates
     }
     /// Get the voting blocs
-    pub const fn voting_blocs(&self) -> &[VotingBloc<B>] {
+    pub fn voting_blocs(&self) -> &[VotingBloc<B>] {
         &self.voting_blocs
     }
     /// Get the method used to determine the winner of the election
@@ -91,13 +94,13 @@ where
             .collect::<Profile<B>>()
     }
     /// Run a single election with the given configuration
-    pub fn run_once(&self, seed: u64) -> impl Outcome {
+    pub fn run_once(&self, seed: u64) -> M::Winner {
         let mut rng = StdRng::seed_from_u64(seed);
         let profile: Profile<B> = self.vote(&mut rng);
         self.method().outcome(self.candidates(), profile)
     }
     /// Run many elections with the given configuration
-    pub fn run_many(&self, iterations: usize, seed: u64) -> Vec<impl Outcome> {
+    pub fn run_many(&self, iterations: usize, seed: u64) -> Vec<M::Winner> {
         let mut rng = StdRng::seed_from_u64(seed);
         (0..iterations)
             .map(|_| rng.random())
@@ -124,7 +127,7 @@ where
     }
 }
 
-impl<const N: usize, const V: usize, B, M> Election<N, V, B, M>
+impl<B, M> Election<B, M>
 where
     B: Ballot,
     M: Method<Ballot = B>,
@@ -1,4 +1,4 @@
-use crate::core::{Ballot, Candidate, Outcome, Profile};
+use crate::core::{Ballot, Candidate, Outcome, Profile, TieBreaker, TieBreaking, Ties};
 
 /// A method of tabulating votes and determining the winner of an election.
 ///
@@ -12,4 +12,20 @@ pub trait Method: Send + Sync + Clone + std::fmt::Debug {
     type Winner: Outcome;
     /// Determines the outcome of an election.
     fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner;
+    /// Determines the outcome of an election, breaking ties along the way (e.g. over which candidate to eliminate) with the given tie-breaker. A tie in the final outcome is left to the caller.
+    ///
+    /// Methods without ties along the way need not implement this, as the tie-breaker is ignored by default.
+    fn outcome_with(
+        &self,
+        candidates: &[Candidate],
+        profile: Profile<Self::Ballot>,
+        _ties: &mut Ties,
+    ) -> Self::Winner {
+        self.outcome(candidates, profile)
+    }
+    /// Breaks the ties of this method, both in its final outcome and along the way, with the given tie-breaker.
+    #[must_use]
+    fn break_ties(self, tie_breaker: TieBreaker) -> TieBreaking<Self> {
+        TieBreaking::new(self, tie_breaker)
+    }
 }
@@ -6,16 +6,138 @@ mod method;
 mod outcome;
 mod preference;
 mod tactic;
+mod tie_breaker;
 mod voting_bloc;
 
 pub use ballot::{Ballot, Cardinal, Nominal, Ordinal, Profile};
 pub use candidate::Candidate;
-pub use election::Election;
+pub use election::{Election, ElectionError};
 pub use method::Method;
-pub use outcome::{MultiWinner, Outcome, SingleWinner};
+pub use outcome::{Lottery, MultiWinner, Outcome, SingleWinner};
 pub use preference::Preference;
 pub use tactic::Tactic;
-pub use voting_bloc::VotingBloc;
+pub use tie_breaker::{TieBreaker, TieBreaking, Ties};
+pub use voting_bloc::{VotingBloc, VotingBlocBuilder};
 
 /// A unique identifier for a candidate
 pub(crate) type Id = usize;
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn election_sizes() {
+        let names = ["A", "B", "C", "D", "E"];
+        for n in 2..=names.len() {
+            let candidates: Vec<Candidate> = (0..n)
+                .map(|id| Candidate::new(id, names[id], None, None))
+                .collect();
+            let voting_blocs = vec![
+                VotingBloc::builder(preferences::Mallows::new((0..n).collect(), 0.5), 40)
+                    .add_tactic(tactics::Identity, 1.0)
+                    .build(),
+            ];
+            let election = Election::new(candidates, voting_blocs, methods::Plurality).unwrap();
+            assert_eq!(election.candidates().len(), n);
+            let outcomes = election.run_many(10, 0);
+            let total: usize = election
+                .tabulate(outcomes)
+                .iter()
+                .map(|(_, count)| count)
+                .sum();
+            assert_eq!(total, 10);
+        }
+    }
+
+    #[test]
+    fn election_duplicate_ids() {
+        let candidates = vec![
+            Candidate::new(0, "A", None, None),
+            Candidate::new(0, "B", None, None),
+        ];
+        let voting_blocs: Vec<VotingBloc<Ordinal>> = vec![];
+        let election = Election::new(candidates, voting_blocs, methods::Plurality);
+        assert!(matches!(
+            election,
+            Err(ElectionError::DuplicateCandidateIds)
+        ));
+    }
+
+    #[test]
+    fn tie_breakers() {
+        let candidates: Vec<Candidate> = ["A", "B", "C"]
+            .into_iter()
+            .enumerate()
+            .map(|(id, name)| Candidate::new(id, name, None, None))
+            .collect();
+        let profile: Profile<Ordinal> = vec![Ordinal(vec![0, 1, 2]), Ordinal(vec![1])].into();
+        let ties = |tie_breaker| {
+            let mut ties = Ties::new(&tie_breaker, &candidates, &profile);
+            ties.record([1.0, 2.0, 3.0]);
+            ties.record([2.0, 1.0, 3.0]);
+            ties.record([4.0, 4.0, 0.0]);
+            ties
+        };
+
+        // B had more votes in the first round, and A in the second.
+        let mut forwards = ties(TieBreaker::Forwards);
+        assert_eq!(forwards.best(&[0, 1]), vec![1]);
+        assert_eq!(forwards.worst(&[0, 1]), vec![0]);
+        let mut backwards = ties(TieBreaker::Backwards);
+        assert_eq!(backwards.best(&[0, 1]), vec![0]);
+        assert_eq!(backwards.worst(&[1, 0]), vec![1]);
+
+        // The Borda counts are 3, 4 and 1.
+        let mut borda = ties(TieBreaker::Borda);
+        assert_eq!(borda.best(&[0, 1, 2]), vec![1]);
+        assert_eq!(
+            borda.rank(&[0, 1, 2], |_| 0),
+            vec![vec![1], vec![0], vec![2]]
+        );
+
+        let mut none = ties(TieBreaker::None);
+        assert_eq!(none.worst(&[2, 0]), vec![0, 2]);
+        assert_eq!(none.rank(&[0, 1, 2], |c| c % 2), vec![vec![1], vec![0, 2]]);
+
+        let mut order = ties(TieBreaker::Order(vec![2]));
+        assert_eq!(
+            order.rank(&[0, 1, 2], |_| 0),
+            vec![vec![2], vec![0], vec![1]]
+        );
+
+        let mut random = ties(TieBreaker::Random(0));
+        let mut ranking = random.rank(&[0, 1, 2], |_| 0).concat();
+        assert_eq!(
+            ties(TieBreaker::Random(0)).rank(&[0, 1, 2], |_| 0).concat(),
+            ranking
+        );
+        ranking.sort_unstable();
+        assert_eq!(ranking, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn fnv_hash() {
+        use super::tie_breaker::Fnv;
+        use std::hash::Hasher;
+        // The published test vectors of 64-bit FNV-1a.
+        let hash = |bytes: &[u8]| {
+            let mut hasher = Fnv::default();
+            hasher.write(bytes);
+            hasher.finish()
+        };
+        assert_eq!(hash(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(hash(b"a"), 0xaf63_dc4c_8601_ec8c);
+        assert_eq!(hash(b"foobar"), 0x8594_4171_f739_67e8);
+    }
+
+    #[test]
+    fn ballot_borda() {
+        assert_eq!(Ordinal(vec![2, 0]).borda(4), vec![2, 0, 3, 0]);
+        assert_eq!(Nominal([1, 2].into()).borda(4), vec![0, 2, 2, 0]);
+        assert_eq!(
+            Cardinal([(0, 5), (1, 0), (2, 5)].into()).borda(4),
+            vec![2, 1, 2, 0]
+        );
+    }
+}
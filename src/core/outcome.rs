@@ -1,15 +1,24 @@
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::{Debug, Display};
 use std::hash::Hash;
 
-use serde::Serialize;
+use ordered_float::OrderedFloat;
+use serde::{Deserialize, Serialize};
 
-use crate::core::{Candidate, Id};
+use crate::core::{Candidate, Id, Ties};
 
-/// The outcome of an election (single-winner or multi-winner)
+/// The outcome of an election (single-winner, multi-winner, or a lottery)
 pub trait Outcome: Send + Sync + Clone + Serialize + Debug + Display + Eq + Hash {
     /// Get the winners of the election
     fn winners(&self) -> Vec<&str>;
+    /// Breaks a tie in the outcome with the given tie-breaker. Only single-winner outcomes can be tied, so other outcomes are returned as is.
+    #[must_use]
+    fn break_tie(self, _ties: &mut Ties) -> Self {
+        self
+    }
+    /// Replaces each candidate in the outcome with the candidate at its id in `candidates`, e.g. to map the outcome of a count over some of the candidates back to the full list.
+    #[must_use]
+    fn relabel(self, candidates: &[Candidate]) -> Self;
 }
 
 impl Outcome for SingleWinner {
@@ -21,6 +30,30 @@ impl Outcome for SingleWinner {
             Self::None => vec![],
         }
     }
+    /// Breaks a tie between the winners, which remain tied if the tie-breaker cannot tell them apart
+    fn break_tie(self, ties: &mut Ties) -> Self {
+        let Self::Tie(winners) = self else {
+            return self;
+        };
+        let best = ties.best(&winners.iter().map(Candidate::id).collect::<Vec<_>>());
+        let mut winners: Vec<Candidate> = winners
+            .into_iter()
+            .filter(|c| best.contains(&c.id()))
+            .collect();
+        match winners.len() {
+            1 => Self::Win(winners.remove(0)),
+            _ => Self::Tie(winners.into_iter().collect()),
+        }
+    }
+    fn relabel(self, candidates: &[Candidate]) -> Self {
+        match self {
+            Self::Win(winner) => Self::Win(candidates[winner.id()].clone()),
+            Self::Tie(winners) => {
+                Self::Tie(winners.iter().map(|c| candidates[c.id()].clone()).collect())
+            }
+            Self::None => Self::None,
+        }
+    }
 }
 
 impl Outcome for MultiWinner {
@@ -31,10 +64,43 @@ impl Outcome for MultiWinner {
             Self::None => vec![],
         }
     }
+    fn relabel(self, candidates: &[Candidate]) -> Self {
+        match self {
+            Self::Elected(elected) => {
+                Self::Elected(elected.iter().map(|c| candidates[c.id()].clone()).collect())
+            }
+            Self::None => Self::None,
+        }
+    }
+}
+
+impl Outcome for Lottery {
+    /// Get the candidates with a positive probability of winning
+    fn winners(&self) -> Vec<&str> {
+        match self {
+            Self::Distribution(probabilities) => probabilities
+                .iter()
+                .filter(|(_, p)| p.0 > 0.0)
+                .map(|(candidate, _)| candidate.name())
+                .collect(),
+            Self::None => vec![],
+        }
+    }
+    fn relabel(self, candidates: &[Candidate]) -> Self {
+        match self {
+            Self::Distribution(probabilities) => Self::Distribution(
+                probabilities
+                    .into_iter()
+                    .map(|(c, p)| (candidates[c.id()].clone(), p))
+                    .collect(),
+            ),
+            Self::None => Self::None,
+        }
+    }
 }
 
 /// The outcome of a single-winner election
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum SingleWinner {
     /// A single winner
     Win(Candidate),
@@ -80,7 +146,7 @@ impl SingleWinner {
 }
 
 /// The outcome of a multi-winner election
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum MultiWinner {
     /// The elected candidates
     Elected(BTreeSet<Candidate>),
@@ -115,6 +181,62 @@ impl MultiWinner {
     }
 }
 
+/// The outcome of a probabilistic election: a probability distribution over the candidates
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Lottery {
+    /// The probability of each candidate winning
+    Distribution(BTreeMap<Candidate, OrderedFloat<f64>>),
+    /// No winner
+    None,
+}
+
+impl Lottery {
+    /// Construct a `Lottery::Distribution()` from the probability of each candidate (indexed by id). Candidates with zero probability are left out.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the ids do not correspond to a candidate.
+    #[must_use]
+    pub fn distribution(candidates: &[Candidate], probabilities: &[f64]) -> Self {
+        Self::Distribution(
+            probabilities
+                .iter()
+                .enumerate()
+                .filter(|(_, p)| **p > 0.0)
+                .map(|(id, p)| {
+                    let candidate = candidates.iter().find(|c| c.id() == id).unwrap();
+                    (candidate.to_owned(), OrderedFloat(*p))
+                })
+                .collect(),
+        )
+    }
+    /// Construct a `Lottery::None()`
+    #[must_use]
+    pub const fn none() -> Self {
+        Self::None
+    }
+    /// The probability of the candidate with the given id winning
+    #[must_use]
+    pub fn probability(&self, id: Id) -> f64 {
+        match self {
+            Self::Distribution(probabilities) => probabilities
+                .iter()
+                .find(|(c, _)| c.id() == id)
+                .map_or(0.0, |(_, p)| p.0),
+            Self::None => 0.0,
+        }
+    }
+    /// The expected utility of the lottery, given the utility of each candidate winning
+    pub fn expected_utility(&self, utility: impl Fn(&Candidate) -> f64) -> f64 {
+        match self {
+            Self::Distribution(probabilities) => {
+                probabilities.iter().map(|(c, p)| p.0 * utility(c)).sum()
+            }
+            Self::None => 0.0,
+        }
+    }
+}
+
 impl Display for SingleWinner {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -153,3 +275,22 @@ impl Display for MultiWinner {
         }
     }
 }
+
+impl Display for Lottery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Distribution(probabilities) => {
+                write!(
+                    f,
+                    "Lottery({})",
+                    probabilities
+                        .iter()
+                        .map(|(c, p)| format!("{}: {:.3}", c.name(), p.0))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            }
+            Self::None => write!(f, "Lottery(None)"),
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use std::any::Any;
 use std::fmt::Debug;
 
 use rand::rngs::StdRng;
@@ -7,7 +8,9 @@ use crate::core::{Ballot, Candidate, Profile};
 /// A preference can be conceptualized as the preferences of the voter as they exist in their head before being written down.
 ///
 /// It is defined as a distribution over possible realizations (ballots), where at election time, one such realization is drawn.
-pub trait Preference<B: Ballot>: Send + Sync + Debug {
+///
+/// Preferences are `Any`, so that a config registry can recognize the types it knows how to write back to a config.
+pub trait Preference<B: Ballot>: Send + Sync + Debug + Any {
     /// Draws a ballot from the preference distribution.
     fn draw(&self, candidates: &[Candidate], rng: &mut StdRng) -> B;
     /// Samples a profile from the preference distribution.
@@ -1,3 +1,4 @@
+use std::any::Any;
 use std::fmt::Debug;
 
 use crate::core::Ballot;
@@ -5,7 +6,9 @@ use crate::core::Ballot;
 /// A tactic is a method of altering one's ballot to maximize (or at least increase) social welfare.
 ///
 /// Note that this implementation considers tactics to be a separate process that occurs *after* realization. This is a limitation of the model.
-pub trait Tactic<B: Ballot>: Send + Sync + Debug {
+///
+/// Tactics are `Any`, so that a config registry can recognize the types it knows how to write back to a config.
+pub trait Tactic<B: Ballot>: Send + Sync + Debug + Any {
     /// Applies the tactic to the given ballot.
     fn apply(&self, ballot: B) -> B;
 }
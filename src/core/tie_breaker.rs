@@ -0,0 +1,271 @@
+use std::cmp::Reverse;
+use std::hash::{Hash, Hasher};
+
+use ordered_float::OrderedFloat;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Ballot, Candidate, Id, Method, Outcome, Profile};
+
+/// A policy for breaking ties between candidates, both in the final outcome and along the way (e.g. over which candidate to eliminate).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TieBreaker {
+    /// Ties are left unbroken: a tied outcome is reported as a tie, and methods fall back to their own rules along the way.
+    #[default]
+    None,
+    /// A tied candidate is drawn uniformly at random. The draws are seeded by the given seed and the profile, so an election always breaks its ties the same way.
+    Random(u64),
+    /// The tied candidate that comes first in the given order wins, and the one that comes last loses. Candidates missing from the order come after those in it, in order of candidate id.
+    Order(Vec<Id>),
+    /// The tied candidate with the most votes in the first round where the tied candidates differed wins, and the one with the fewest loses.
+    Forwards,
+    /// The tied candidate with the most votes in the most recent round where the tied candidates differed wins, and the one with the fewest loses.
+    Backwards,
+    /// The tied candidate with the highest Borda count wins, and the one with the lowest loses.
+    Borda,
+}
+
+/// The tie-breaker of a single count, holding what its policy needs: the random draws, the Borda counts, or the counts of every round so far.
+///
+/// Methods record the counts of each round with [`Ties::record`], and ask for the [`Ties::best`] or [`Ties::worst`] of the candidates tied in it. The tie-breaker may not be able to tell the tied candidates apart, in which case several are returned, and the method falls back to its own rule.
+#[derive(Debug, Clone)]
+pub struct Ties {
+    rule: Rule,
+    /// The counts of each candidate in every round so far, kept only by the policies that look at them.
+    history: Vec<Vec<f64>>,
+}
+
+#[derive(Debug, Clone)]
+enum Rule {
+    None,
+    Random(Box<StdRng>),
+    /// The position of each candidate in the tie-breaking order (lower wins).
+    Order(Vec<usize>),
+    Forwards,
+    Backwards,
+    /// The Borda count of each candidate (higher wins).
+    Borda(Vec<usize>),
+}
+
+impl Ties {
+    /// Prepares a tie-breaker to count the given profile.
+    #[must_use]
+    pub fn new<B: Ballot>(
+        tie_breaker: &TieBreaker,
+        candidates: &[Candidate],
+        profile: &Profile<B>,
+    ) -> Self {
+        let n = candidates.len();
+        let rule = match tie_breaker {
+            TieBreaker::None => Rule::None,
+            TieBreaker::Random(seed) => {
+                // the ballots are hashed with FNV-1a rather than the standard library's randomly keyed hasher, so the draws depend only on the seed and the profile
+                let mut hasher = Fnv::default();
+                profile[..].hash(&mut hasher);
+                Rule::Random(Box::new(StdRng::seed_from_u64(seed ^ hasher.finish())))
+            }
+            TieBreaker::Order(order) => {
+                let mut position: Vec<usize> = (0..n).map(|c| order.len() + c).collect();
+                for (i, &c) in order.iter().enumerate().filter(|(_, c)| **c < n) {
+                    position[c] = position[c].min(i);
+                }
+                Rule::Order(position)
+            }
+            TieBreaker::Forwards => Rule::Forwards,
+            TieBreaker::Backwards => Rule::Backwards,
+            TieBreaker::Borda => Rule::Borda(profile.iter().fold(vec![0; n], |mut borda, b| {
+                for (total, points) in borda.iter_mut().zip(b.borda(n)) {
+                    *total += points;
+                }
+                borda
+            })),
+        };
+        Self {
+            rule,
+            history: Vec::new(),
+        }
+    }
+    /// A tie-breaker that leaves every tie unbroken.
+    #[must_use]
+    pub const fn none() -> Self {
+        Self {
+            rule: Rule::None,
+            history: Vec::new(),
+        }
+    }
+    /// A tie-breaker for a count over some of the candidates, where candidate `i` of the count is candidate `ids[i]` of this one.
+    pub(crate) fn restrict(&mut self, ids: &[Id]) -> Self {
+        let rule = match &mut self.rule {
+            Rule::None => Rule::None,
+            Rule::Random(rng) => Rule::Random(Box::new(StdRng::from_rng(rng.as_mut()))),
+            Rule::Order(position) => Rule::Order(
+                ids.iter()
+                    .map(|&c| position.get(c).copied().unwrap_or(usize::MAX))
+                    .collect(),
+            ),
+            Rule::Forwards => Rule::Forwards,
+            Rule::Backwards => Rule::Backwards,
+            Rule::Borda(borda) => Rule::Borda(
+                ids.iter()
+                    .map(|&c| borda.get(c).copied().unwrap_or(0))
+                    .collect(),
+            ),
+        };
+        Self {
+            rule,
+            history: Vec::new(),
+        }
+    }
+    /// Records the counts of each candidate (indexed by id) in a round, for the policies that look back at previous rounds.
+    pub fn record(&mut self, counts: impl IntoIterator<Item = f64>) {
+        if matches!(self.rule, Rule::Forwards | Rule::Backwards) {
+            self.history.push(counts.into_iter().collect());
+        }
+    }
+    /// The tied candidates that win the tie: a single candidate, unless the tie-breaker cannot tell them apart.
+    pub fn best(&mut self, tied: &[Id]) -> Vec<Id> {
+        self.select(tied, true)
+    }
+    /// The tied candidates that lose the tie: a single candidate, unless the tie-breaker cannot tell them apart.
+    pub fn worst(&mut self, tied: &[Id]) -> Vec<Id> {
+        self.select(tied, false)
+    }
+    /// Orders candidates from the highest `key` to the lowest, breaking ties between equal keys.
+    ///
+    /// Returns groups of candidates that the tie-breaker could not tell apart, from best to worst, each in order of candidate id.
+    pub fn rank<K: Ord>(&mut self, candidates: &[Id], key: impl Fn(Id) -> K) -> Vec<Vec<Id>> {
+        let mut sorted = candidates.to_vec();
+        sorted.sort_by_key(|&c| (Reverse(key(c)), c));
+        let mut groups = Vec::new();
+        for tied in sorted.chunk_by(|&a, &b| key(a) == key(b)) {
+            let mut tied = tied.to_vec();
+            while !tied.is_empty() {
+                let best = self.best(&tied);
+                if best.is_empty() || best.len() == tied.len() {
+                    groups.push(tied);
+                    break;
+                }
+                tied.retain(|c| !best.contains(c));
+                groups.push(best);
+            }
+        }
+        groups
+    }
+    fn select(&mut self, tied: &[Id], best: bool) -> Vec<Id> {
+        let mut tied = tied.to_vec();
+        tied.sort_unstable();
+        if tied.len() <= 1 {
+            return tied;
+        }
+        match &mut self.rule {
+            Rule::None => {}
+            Rule::Random(rng) => tied = vec![tied[rng.random_range(0..tied.len())]],
+            Rule::Order(position) => {
+                keep_extreme(
+                    &mut tied,
+                    |c| Reverse(position.get(c).copied().unwrap_or(usize::MAX)),
+                    best,
+                );
+            }
+            Rule::Forwards => {
+                for round in &self.history {
+                    keep_extreme(&mut tied, |c| count(round, c), best);
+                }
+            }
+            Rule::Backwards => {
+                for round in self.history.iter().rev() {
+                    keep_extreme(&mut tied, |c| count(round, c), best);
+                }
+            }
+            Rule::Borda(borda) => {
+                keep_extreme(&mut tied, |c| borda.get(c).copied().unwrap_or(0), best);
+            }
+        }
+        tied
+    }
+}
+
+/// The 64-bit FNV-1a hash, which (unlike the standard library's hasher) is not randomly keyed, so that seeded draws can be reproduced.
+pub(super) struct Fnv(u64);
+
+impl Default for Fnv {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for Fnv {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    /// Lengths and ids are hashed as 64-bit little-endian integers, whatever the width of `usize`.
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes());
+    }
+}
+
+/// The count of a candidate in a round, ordered so that it can be compared.
+fn count(round: &[f64], c: Id) -> OrderedFloat<f64> {
+    OrderedFloat(round.get(c).copied().unwrap_or(0.0))
+}
+
+/// Keeps the candidates with the highest key (or the lowest, if not `best`).
+fn keep_extreme<K: Ord>(tied: &mut Vec<Id>, key: impl Fn(Id) -> K, best: bool) {
+    let extreme = if best {
+        tied.iter().map(|&c| key(c)).max()
+    } else {
+        tied.iter().map(|&c| key(c)).min()
+    };
+    if let Some(extreme) = extreme {
+        tied.retain(|&c| key(c) == extreme);
+    }
+}
+
+/// A method whose ties are broken by a tie-breaker, both in its final outcome and along the way.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TieBreaking<M> {
+    method: M,
+    tie_breaker: TieBreaker,
+}
+
+impl<M: Method> TieBreaking<M> {
+    /// Breaks the ties of a method with the given tie-breaker.
+    #[must_use]
+    pub const fn new(method: M, tie_breaker: TieBreaker) -> Self {
+        Self {
+            method,
+            tie_breaker,
+        }
+    }
+    /// The method whose ties are broken
+    #[must_use]
+    pub const fn method(&self) -> &M {
+        &self.method
+    }
+    /// The tie-breaker
+    #[must_use]
+    pub const fn tie_breaker(&self) -> &TieBreaker {
+        &self.tie_breaker
+    }
+}
+
+impl<M: Method> Method for TieBreaking<M> {
+    type Ballot = M::Ballot;
+    type Winner = M::Winner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        let mut ties = Ties::new(&self.tie_breaker, candidates, &profile);
+        self.method
+            .outcome_with(candidates, profile, &mut ties)
+            .break_tie(&mut ties)
+    }
+}
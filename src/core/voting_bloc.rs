@@ -97,12 +97,28 @@ impl<B: Ballot> VotingBlocBuilder<B> {
             members,
         }
     }
+    /// Create a new voting bloc builder from a shared preference
+    pub fn shared(preference: Arc<dyn Preference<B>>, members: usize) -> Self {
+        Self {
+            preference,
+            strategy: Vec::new(),
+            members,
+        }
+    }
     /// Add a tactic to the voting bloc
+    #[must_use]
     pub fn add_tactic(mut self, tactic: impl Tactic<B> + 'static, weight: f32) -> Self {
         self.strategy.push((Arc::new(tactic), weight));
         self
     }
+    /// Add a shared tactic to the voting bloc
+    #[must_use]
+    pub fn add_shared_tactic(mut self, tactic: Arc<dyn Tactic<B>>, weight: f32) -> Self {
+        self.strategy.push((tactic, weight));
+        self
+    }
     /// Build the voting bloc
+    #[must_use]
     pub fn build(mut self) -> VotingBloc<B> {
         if self.strategy.is_empty() {
             self.strategy.push((Arc::new(Identity), 1.0f32));
@@ -45,6 +45,7 @@
 //! let outcomes = election.run_many(1_000, 0);
 //! election.display(&outcomes);
 //! ```
+pub mod config;
 pub mod core;
 pub mod methods;
 pub mod preferences;
@@ -1,28 +1,209 @@
-use kingmaker::prelude::*;
-
-fn main() {
-    // configure election
-    let candidates = [
-        Candidate::new(0, "Alice", Some("DEM"), Default::default()),
-        Candidate::new(1, "Bliar", Some("REP"), Default::default()),
-        Candidate::new(2, "Cybil", None, Default::default()),
-    ];
-    let voting_blocks = [
-        VotingBloc::builder(preferences::Mallows::new(vec![0, 2, 1], 1.4), 40)
-            .add_tactic(tactics::Burial::new(vec![1]), 0.8) // Do not vote for the opposition!
-            .add_tactic(tactics::Identity, 0.2)
-            .build(),
-        VotingBloc::builder(preferences::Mallows::new(vec![1, 2, 0], 1.0), 45)
-            .add_tactic(tactics::Burial::new(vec![0]), 0.8) // Do not vote for the opposition!
-            .add_tactic(tactics::Identity, 0.2)
-            .build(),
-        VotingBloc::builder(preferences::Mallows::new(vec![2, 0, 1], 1.2), 15)
-            .add_tactic(tactics::Identity, 0.8)
-            .add_tactic(tactics::Compromise::new(vec![0]), 0.2) // Compromise for A (DEM) instead
-            .build(),
-    ];
-    let election = Election::new(candidates, voting_blocks, methods::IRV).unwrap();
-    // run election
-    let outcomes = election.run_many(10_000, 0);
-    election.display(&outcomes);
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+
+use kingmaker::config::ElectionFile;
+
+mod cli;
+
+use cli::{BallotFormat, CliError, Format};
+
+/// Simulate, tabulate and compare elections, with or without strategic voting.
+///
+/// Methods and preference models are given by name (e.g. `irv`), or with their parameters as an inline TOML table (e.g. `{ name = "stv", seats = 2 }`).
+#[derive(Debug, Parser)]
+#[command(name = "kingmaker", version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Simulate an election config (TOML or JSON) many times and tabulate the outcomes
+    Run {
+        /// The election config
+        config: PathBuf,
+        /// The number of elections to simulate
+        #[arg(short = 'n', long, default_value_t = 1000)]
+        iterations: usize,
+        /// The seed of the random number generator
+        #[arg(short, long, default_value_t = 0)]
+        seed: u64,
+        /// The output format
+        #[arg(short, long, value_enum, default_value_t)]
+        format: Format,
+    },
+    /// Count the ballots in a ballot file with a method
+    Tabulate {
+        /// The ballot file (JSON, CSV or BLT)
+        ballots: PathBuf,
+        /// The method
+        #[arg(short, long)]
+        method: String,
+        /// The ballot type of CSV files (nominal, ordinal or cardinal)
+        #[arg(short, long)]
+        ballot: Option<String>,
+        /// The format of the ballot file, if not given by its extension
+        #[arg(long, value_enum)]
+        from: Option<BallotFormat>,
+        /// The output format
+        #[arg(short, long, value_enum, default_value_t)]
+        format: Format,
+    },
+    /// Run several methods on the same profiles, drawn from an election config or read from a ballot file
+    Compare {
+        /// The election config
+        #[arg(long, required_unless_present = "ballots", conflicts_with = "ballots")]
+        config: Option<PathBuf>,
+        /// The ballot file (JSON, CSV or BLT)
+        #[arg(long)]
+        ballots: Option<PathBuf>,
+        /// The methods to compare
+        #[arg(short, long, required = true)]
+        method: Vec<String>,
+        /// The number of elections to simulate
+        #[arg(short = 'n', long, default_value_t = 1000)]
+        iterations: usize,
+        /// The seed of the random number generator
+        #[arg(short, long, default_value_t = 0)]
+        seed: u64,
+        /// The ballot type of CSV files (nominal, ordinal or cardinal)
+        #[arg(short, long)]
+        ballot: Option<String>,
+        /// The format of the ballot file, if not given by its extension
+        #[arg(long, value_enum)]
+        from: Option<BallotFormat>,
+        /// The output format
+        #[arg(short, long, value_enum, default_value_t)]
+        format: Format,
+    },
+    /// Generate a synthetic profile from a preference model
+    Generate {
+        /// The names of the candidates
+        #[arg(short, long, value_delimiter = ',', required = true)]
+        candidates: Vec<String>,
+        /// The preference model
+        #[arg(short, long)]
+        preference: String,
+        /// The number of voters
+        #[arg(short, long, default_value_t = 100)]
+        voters: usize,
+        /// The ballot type (nominal, ordinal or cardinal)
+        #[arg(short, long, default_value = "ordinal")]
+        ballot: String,
+        /// The seed of the random number generator
+        #[arg(short, long, default_value_t = 0)]
+        seed: u64,
+        /// The file to write the ballots to, instead of standard output
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        /// The format of the ballots, if not given by the extension of the output file (JSON by default)
+        #[arg(long, value_enum)]
+        to: Option<BallotFormat>,
+    },
+    /// Convert a ballot file between formats
+    Convert {
+        /// The ballot file to read
+        input: PathBuf,
+        /// The ballot file to write
+        output: PathBuf,
+        /// The ballot type of CSV files (nominal, ordinal or cardinal)
+        #[arg(short, long)]
+        ballot: Option<String>,
+        /// The format of the input file, if not given by its extension
+        #[arg(long, value_enum)]
+        from: Option<BallotFormat>,
+        /// The format of the output file, if not given by its extension
+        #[arg(long, value_enum)]
+        to: Option<BallotFormat>,
+    },
+}
+
+fn execute(command: Command) -> Result<String, CliError> {
+    match command {
+        Command::Run {
+            config,
+            iterations,
+            seed,
+            format,
+        } => cli::run(&ElectionFile::load(config)?, iterations, seed)?.render(format),
+        Command::Tabulate {
+            ballots,
+            method,
+            ballot,
+            from,
+            format,
+        } => {
+            let file = cli::read_ballots(&ballots, from, ballot.as_deref())?;
+            cli::tabulate(&file, &method)?.render(format)
+        }
+        Command::Compare {
+            config,
+            ballots,
+            method,
+            iterations,
+            seed,
+            ballot,
+            from,
+            format,
+        } => {
+            let table = match (config, ballots) {
+                (Some(config), _) => {
+                    cli::compare(&ElectionFile::load(config)?, &method, iterations, seed)?
+                }
+                (None, Some(ballots)) => {
+                    let file = cli::read_ballots(&ballots, from, ballot.as_deref())?;
+                    cli::compare_ballots(&file, &method)?
+                }
+                (None, None) => unreachable!("clap requires a config or a ballot file"),
+            };
+            table.render(format)
+        }
+        Command::Generate {
+            candidates,
+            preference,
+            voters,
+            ballot,
+            seed,
+            output,
+            to,
+        } => {
+            let file = cli::generate(&candidates, &preference, voters, &ballot, seed)?;
+            match output {
+                Some(path) => {
+                    let format = BallotFormat::resolve(to, &path)?;
+                    cli::write(&path, &file.write(format)?)?;
+                    Ok(String::new())
+                }
+                None => file.write(to.unwrap_or(BallotFormat::Json)),
+            }
+        }
+        Command::Convert {
+            input,
+            output,
+            ballot,
+            from,
+            to,
+        } => {
+            let file = cli::read_ballots(&input, from, ballot.as_deref())?;
+            let format = BallotFormat::resolve(to, &output)?;
+            cli::write(&output, &file.write(format)?)?;
+            Ok(String::new())
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    match execute(Cli::parse().command) {
+        Ok(output) => {
+            print!("{output}");
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
 }
@@ -1,8 +1,9 @@
+use crate::config::{Component, Describe};
 use crate::core::{Candidate, Id, Method, Nominal, Profile, SingleWinner};
 use crate::methods::find_candidates_with_value;
 
 /// A single-winner, nominal voting method. The winner is the candidate(s) with the most approvals.
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Approval;
 
 impl Method for Approval {
@@ -25,3 +26,9 @@ impl Method for Approval {
         }
     }
 }
+
+impl Describe for Approval {
+    fn describe(&self) -> Component {
+        Component::new("approval")
+    }
+}
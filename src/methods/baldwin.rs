@@ -0,0 +1,45 @@
+use crate::config::{Component, Describe};
+use crate::core::{Candidate, Id, Method, Ordinal, Profile, SingleWinner, Ties};
+use crate::methods::as_f64;
+use crate::methods::borda::borda_scores;
+use crate::methods::instant_runoff::{Round, eliminate};
+
+/// A single-winner, ranked, Condorcet-consistent voting method. In each round, the Borda scores of the remaining candidates are computed and the candidate with the lowest score is eliminated, until a single candidate remains. Candidates tied for the lowest score are all eliminated at once, unless a tie-breaker picks one of them.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Baldwin;
+
+impl Method for Baldwin {
+    type Ballot = Ordinal;
+    type Winner = SingleWinner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        self.outcome_with(candidates, profile, &mut Ties::none())
+    }
+    fn outcome_with(
+        &self,
+        candidates: &[Candidate],
+        profile: Profile<Self::Ballot>,
+        ties: &mut Ties,
+    ) -> Self::Winner {
+        eliminate(candidates, &profile, ties, |ballots, remaining, ties| {
+            if remaining.len() == 1 {
+                return Round::Elect(remaining.to_vec());
+            }
+            let scores = borda_scores(candidates, ballots, remaining);
+            ties.record(scores.iter().copied().map(as_f64));
+            let min_score = remaining.iter().map(|&c| scores[c]).min().unwrap_or(0);
+            let losers: Vec<Id> = remaining
+                .iter()
+                .copied()
+                .filter(|&c| scores[c] == min_score)
+                .collect();
+            Round::EliminateOne(losers)
+        })
+    }
+}
+
+impl Describe for Baldwin {
+    fn describe(&self) -> Component {
+        Component::new("baldwin")
+    }
+}
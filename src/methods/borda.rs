@@ -1,8 +1,9 @@
+use crate::config::{Component, Describe};
 use crate::core::{Candidate, Id, Method, Ordinal, Profile, SingleWinner};
-use crate::methods::find_candidates_with_value;
+use crate::methods::Positional;
 
-/// A single-winner, ranked voting method. Each rank (in the ballot) is given a value corresponding to its order. With n candidates, 1st is `n-1`, 2nd is `n-2`, and so on, with last having a value of `0`. The candidate whose sum of ranks is highest is the winner.
-#[derive(Debug, Clone, serde::Serialize)]
+/// A single-winner, ranked voting method. Each rank (in the ballot) is given a value corresponding to its order. With n candidates, 1st is `n-1`, 2nd is `n-2`, and so on, with last having a value of `0`. The candidate whose sum of ranks is highest is the winner. This is [`Positional::borda`] for the number of candidates in the election, so candidates left off a truncated ballot receive no points.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Borda;
 
 impl Method for Borda {
@@ -10,21 +11,24 @@ impl Method for Borda {
     type Winner = SingleWinner;
     #[inline]
     fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
-        let ranking_score =
-            profile
-                .iter()
-                .fold(vec![0usize; candidates.len()], |mut ranking_score, b| {
-                    for (i, candidate) in b.iter().enumerate() {
-                        ranking_score[*candidate] += b.len() - i;
-                    }
-                    ranking_score
-                });
-        let max_ranking = ranking_score.iter().max().unwrap();
-        let winners: Vec<Id> = find_candidates_with_value(&ranking_score, max_ranking);
-        match winners.len() {
-            0 => SingleWinner::none(),
-            1 => SingleWinner::win(candidates, winners[0]),
-            _ => SingleWinner::tie(candidates, &winners),
-        }
+        Positional::borda(candidates.len()).outcome(candidates, profile)
     }
 }
+
+impl Describe for Borda {
+    fn describe(&self) -> Component {
+        Component::new("borda")
+    }
+}
+
+/// Computes the Borda scores over ballots restricted to `remaining` candidates. Each ballot gives `m - 1` points to its first choice, `m - 2` to its second, and so on, where `m` is the number of remaining candidates. Unranked candidates receive no points.
+pub fn borda_scores(candidates: &[Candidate], ballots: &[Ordinal], remaining: &[Id]) -> Vec<usize> {
+    ballots
+        .iter()
+        .fold(vec![0usize; candidates.len()], |mut scores, b| {
+            for (i, candidate) in b.iter().enumerate() {
+                scores[*candidate] += remaining.len().saturating_sub(i + 1);
+            }
+            scores
+        })
+}
@@ -0,0 +1,51 @@
+use std::cmp::Ordering;
+
+use crate::config::{Component, Describe};
+use crate::core::{Candidate, Method, Ordinal, Profile, SingleWinner, Ties};
+use crate::methods::instant_runoff::{Round, eliminate, first_place_counts};
+use crate::methods::{as_f64, pairwise_preferences};
+
+/// A single-winner, ranked, Condorcet-consistent voting method (Bottom-Two-Runoff IRV).
+///
+/// In each round, the two candidates with the fewest first-place votes face off, and the loser of their pairwise contest is eliminated.
+///
+/// Candidates tied for the fewest first-place votes are ordered by the tie-breaker, falling back to candidate id, so the bottom two are those with the fewest votes and the highest ids. If the bottom two are tied pairwise, both are eliminated, unless the tie-breaker picks one of them.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct BottomTwoRunoff;
+
+impl Method for BottomTwoRunoff {
+    type Ballot = Ordinal;
+    type Winner = SingleWinner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        self.outcome_with(candidates, profile, &mut Ties::none())
+    }
+    fn outcome_with(
+        &self,
+        candidates: &[Candidate],
+        profile: Profile<Self::Ballot>,
+        ties: &mut Ties,
+    ) -> Self::Winner {
+        eliminate(candidates, &profile, ties, |ballots, remaining, ties| {
+            if remaining.len() == 1 {
+                return Round::Elect(remaining.to_vec());
+            }
+            let fpc = first_place_counts(candidates, ballots);
+            ties.record(fpc.iter().copied().map(as_f64));
+            let ranking: Vec<_> = ties.rank(remaining, |c| fpc[c]).concat();
+            let (a, b) = (ranking[ranking.len() - 1], ranking[ranking.len() - 2]);
+            let d = pairwise_preferences(candidates, ballots);
+            match d[a][b].cmp(&d[b][a]) {
+                Ordering::Greater => Round::Eliminate(vec![b]),
+                Ordering::Less => Round::Eliminate(vec![a]),
+                Ordering::Equal => Round::EliminateOne(vec![a, b]),
+            }
+        })
+    }
+}
+
+impl Describe for BottomTwoRunoff {
+    fn describe(&self) -> Component {
+        Component::new("bottom_two_runoff")
+    }
+}
@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::{Component, Describe};
+use crate::core::{Candidate, Id, Method, Ordinal, Profile, SingleWinner, Ties};
+use crate::methods::{as_f64, find_candidates_with_value};
+
+/// A single-winner, ranked voting method (a median-rank method).
+///
+/// First-place votes are counted, and if no candidate has a majority, second-place votes are added to the count, then third-place votes, and so on until some candidate has a majority.
+///
+/// The variant determines how the winner is chosen when several candidates reach a majority in the same round. If the ballots run out before anyone reaches a majority, the candidates with the most votes win. A tie-breaker looks back at the counts of each round, e.g. to favor the candidate that led in the earliest round.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Bucklin {
+    /// The candidate with the most votes in the round wins.
+    MostVotes,
+    /// All candidates that reach a majority in the round tie.
+    Tie,
+}
+
+impl Method for Bucklin {
+    type Ballot = Ordinal;
+    type Winner = SingleWinner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        self.outcome_with(candidates, profile, &mut Ties::none())
+    }
+    fn outcome_with(
+        &self,
+        candidates: &[Candidate],
+        profile: Profile<Self::Ballot>,
+        ties: &mut Ties,
+    ) -> Self::Winner {
+        let majority = profile.len() / 2 + 1;
+        let depth = profile.iter().map(|b| b.len()).max().unwrap_or(0);
+        let mut counts = vec![0usize; candidates.len()];
+        for rank in 0..depth {
+            for b in profile.iter() {
+                if let Some(candidate) = b.get(rank) {
+                    counts[*candidate] += 1;
+                }
+            }
+            ties.record(counts.iter().copied().map(as_f64));
+            if counts.iter().any(|&count| count >= majority) {
+                break;
+            }
+        }
+
+        let max_count = counts.iter().max().copied().unwrap_or(0);
+        let winners: Vec<Id> = match self {
+            Self::Tie if max_count >= majority => counts
+                .iter()
+                .enumerate()
+                .filter_map(|(c, &count)| (count >= majority).then_some(c))
+                .collect(),
+            Self::Tie | Self::MostVotes => find_candidates_with_value(&counts, &max_count),
+        };
+        match winners.len() {
+            0 => SingleWinner::none(),
+            1 => SingleWinner::win(candidates, winners[0]),
+            _ => SingleWinner::tie(candidates, &winners),
+        }
+    }
+}
+
+impl Describe for Bucklin {
+    fn describe(&self) -> Component {
+        let variant = match self {
+            Self::MostVotes => "most_votes",
+            Self::Tie => "tie",
+        };
+        Component::new("bucklin").param("variant", variant)
+    }
+}
@@ -0,0 +1,128 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::{Component, Describe};
+use crate::core::{Candidate, Id, Method, MultiWinner, Ordinal, Profile, Ties};
+use crate::methods::proportional_approval::{check_committees, for_each_committee};
+use crate::methods::{MethodError, Solver, as_f64, best_of, choose_committee};
+
+/// A multi-winner, ranked voting method that elects the committee that best represents the voters.
+///
+/// Each voter is represented by their favorite committee member, and the committee minimizes the total Borda misrepresentation: the number of candidates each voter ranks above their representative.
+///
+/// Candidates left off a ballot are considered ranked last. The exact solver searches every committee, which is only feasible for small elections (when there are more than a million committees, it falls back to the approximate solver), and breaks ties between optimal committees with the tie-breaker, and then in favor of the lexicographically smallest committee. The approximate solver greedily adds the candidate that reduces the misrepresentation the most, breaking ties with the tie-breaker.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChamberlinCourant {
+    seats: usize,
+    solver: Solver,
+}
+
+impl ChamberlinCourant {
+    /// Creates a new instance of Chamberlin-Courant with the specified number of seats and solver.
+    #[must_use]
+    pub const fn new(seats: usize, solver: Solver) -> Self {
+        Self { seats, solver }
+    }
+}
+
+impl Method for ChamberlinCourant {
+    type Ballot = Ordinal;
+    type Winner = MultiWinner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        self.outcome_with(candidates, profile, &mut Ties::none())
+    }
+    fn outcome_with(
+        &self,
+        candidates: &[Candidate],
+        profile: Profile<Self::Ballot>,
+        ties: &mut Ties,
+    ) -> Self::Winner {
+        let seats = self.seats.min(candidates.len());
+        let misrepresentation = misrepresentation(candidates, &profile);
+        let committee = match self.solver {
+            Solver::Exact => exact(&misrepresentation, candidates.len(), seats, ties)
+                .unwrap_or_else(|_| greedy(&misrepresentation, candidates.len(), seats, ties)),
+            Solver::Approximate => greedy(&misrepresentation, candidates.len(), seats, ties),
+        };
+        if committee.is_empty() {
+            return MultiWinner::none();
+        }
+        MultiWinner::seats(candidates, &committee)
+    }
+}
+
+impl Describe for ChamberlinCourant {
+    fn describe(&self) -> Component {
+        Component::new("chamberlin_courant")
+            .param("seats", self.seats)
+            .param("solver", self.solver.name())
+    }
+}
+
+/// The total misrepresentation of the voters by a committee, where each voter is represented by their favorite member.
+fn cost(misrepresentation: &[Vec<usize>], committee: &[Id]) -> usize {
+    misrepresentation
+        .iter()
+        .map(|m| committee.iter().map(|&c| m[c]).min().unwrap_or(0))
+        .sum()
+}
+
+/// Finds the least misrepresenting committees by searching every committee.
+fn exact(
+    misrepresentation: &[Vec<usize>],
+    candidates: usize,
+    seats: usize,
+    ties: &mut Ties,
+) -> Result<Vec<Id>, MethodError> {
+    check_committees(candidates, seats)?;
+    let mut best: Option<(usize, Vec<Vec<Id>>)> = None;
+    for_each_committee(candidates, seats, |committee| {
+        let cost = cost(misrepresentation, committee);
+        match &mut best {
+            Some((best, optimal)) if cost == *best => optimal.push(committee.to_vec()),
+            Some((best, _)) if cost > *best => {}
+            _ => best = Some((cost, vec![committee.to_vec()])),
+        }
+    });
+    Ok(best
+        .map(|(_, optimal)| choose_committee(optimal, ties))
+        .unwrap_or_default())
+}
+
+/// Elects a committee one member at a time, each time adding the candidate that reduces the misrepresentation the most.
+fn greedy(
+    misrepresentation: &[Vec<usize>],
+    candidates: usize,
+    seats: usize,
+    ties: &mut Ties,
+) -> Vec<Id> {
+    let mut committee: Vec<Id> = Vec::with_capacity(seats);
+    while committee.len() < seats {
+        let contenders = (0..candidates).filter(|c| !committee.contains(c));
+        let extended_cost = |c: Id| {
+            let mut extended = committee.clone();
+            extended.push(c);
+            -as_f64(cost(misrepresentation, &extended))
+        };
+        let Some(next) = best_of(contenders, extended_cost, ties) else {
+            break;
+        };
+        committee.push(next);
+    }
+    committee
+}
+
+/// Computes the Borda misrepresentation of each candidate for each voter, i.e. the number of candidates the voter ranks above them. Unranked candidates are considered ranked last.
+pub fn misrepresentation(candidates: &[Candidate], profile: &Profile<Ordinal>) -> Vec<Vec<usize>> {
+    let worst = candidates.len().saturating_sub(1);
+    profile
+        .iter()
+        .map(|b| {
+            let mut m = vec![worst; candidates.len()];
+            for (position, &c) in b.iter().enumerate() {
+                m[c] = position;
+            }
+            m
+        })
+        .collect()
+}
@@ -0,0 +1,65 @@
+use crate::config::{Component, Describe};
+use crate::core::{Candidate, Id, Method, Ordinal, Profile, SingleWinner, Ties};
+use crate::methods::as_f64;
+use crate::methods::instant_runoff::{Round, eliminate, first_place_counts};
+
+/// A single-winner, ranked voting method.
+///
+/// If no candidate has a majority of first-place votes, the candidate ranked last by the most voters is eliminated, and the process repeats with the remaining candidates.
+///
+/// The last-place vote of a truncated ballot goes to its lowest ranked candidate. Candidates tied for the most last-place votes are all eliminated at once, unless a tie-breaker picks one of them, in which case the counts of previous rounds are first-place votes.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Coombs;
+
+impl Method for Coombs {
+    type Ballot = Ordinal;
+    type Winner = SingleWinner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        self.outcome_with(candidates, profile, &mut Ties::none())
+    }
+    fn outcome_with(
+        &self,
+        candidates: &[Candidate],
+        profile: Profile<Self::Ballot>,
+        ties: &mut Ties,
+    ) -> Self::Winner {
+        let majority = profile.len() / 2 + 1;
+        eliminate(candidates, &profile, ties, |ballots, remaining, ties| {
+            let fpc = first_place_counts(candidates, ballots);
+            ties.record(fpc.iter().copied().map(as_f64));
+            let max_first_place_votes = remaining.iter().map(|&c| fpc[c]).max().unwrap_or(0);
+            if max_first_place_votes >= majority || remaining.len() == 1 {
+                return Round::Elect(
+                    remaining
+                        .iter()
+                        .copied()
+                        .filter(|&c| fpc[c] == max_first_place_votes)
+                        .collect(),
+                );
+            }
+            // Eliminate a candidate with the most last-place votes.
+            let lpc = ballots
+                .iter()
+                .fold(vec![0usize; candidates.len()], |mut lpc, b| {
+                    if let Some(last_place_candidate) = b.last() {
+                        lpc[*last_place_candidate] += 1;
+                    }
+                    lpc
+                });
+            let max_last_place_votes = remaining.iter().map(|&c| lpc[c]).max().unwrap_or(0);
+            let losers: Vec<Id> = remaining
+                .iter()
+                .copied()
+                .filter(|&c| lpc[c] == max_last_place_votes)
+                .collect();
+            Round::EliminateOne(losers)
+        })
+    }
+}
+
+impl Describe for Coombs {
+    fn describe(&self) -> Component {
+        Component::new("coombs")
+    }
+}
@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::{Component, Describe};
+use crate::core::{Candidate, Id, Method, Ordinal, Profile, SingleWinner};
+use crate::methods::{find_candidates_with_value, pairwise_preferences};
+
+/// A single-winner, ranked, Condorcet-consistent voting method.
+///
+/// Each candidate scores a point for every pairwise victory and a configurable fraction of a point for every pairwise tie. The candidate with the highest score wins.
+///
+/// The classic Copeland method scores ties as `0.5`, while Llull's method scores them as `1`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Copeland {
+    tie_score: f64,
+}
+
+impl Copeland {
+    /// Creates a new instance of Copeland with the specified score for pairwise ties.
+    #[must_use]
+    pub const fn new(tie_score: f64) -> Self {
+        Self { tie_score }
+    }
+    /// Creates a new instance of Llull's method, which scores pairwise ties as full victories.
+    #[must_use]
+    pub const fn llull() -> Self {
+        Self::new(1.0)
+    }
+}
+
+impl Default for Copeland {
+    fn default() -> Self {
+        Self::new(0.5)
+    }
+}
+
+impl Method for Copeland {
+    type Ballot = Ordinal;
+    type Winner = SingleWinner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        let n = candidates.len();
+        let d = pairwise_preferences(candidates, &profile);
+        let scores: Vec<f64> = (0..n)
+            .map(|i| {
+                (0..n)
+                    .filter(|&j| j != i)
+                    .map(|j| match d[i][j].cmp(&d[j][i]) {
+                        std::cmp::Ordering::Greater => 1.0,
+                        std::cmp::Ordering::Equal => self.tie_score,
+                        std::cmp::Ordering::Less => 0.0,
+                    })
+                    .sum()
+            })
+            .collect();
+        let max_score = scores.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let winners: Vec<Id> = find_candidates_with_value(&scores, &max_score);
+        match winners.len() {
+            0 => SingleWinner::none(),
+            1 => SingleWinner::win(candidates, winners[0]),
+            _ => SingleWinner::tie(candidates, &winners),
+        }
+    }
+}
+
+impl Describe for Copeland {
+    fn describe(&self) -> Component {
+        Component::new("copeland").param("tie_score", self.tie_score)
+    }
+}
@@ -0,0 +1,219 @@
+use std::marker::PhantomData;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::{Component, Describe};
+use crate::core::{
+    Candidate, Cardinal, Id, Method, MultiWinner, Outcome, Profile, SingleWinner, Ties,
+};
+use crate::methods::{find_candidates_with_value, most_votes};
+
+/// A single-winner or multi-winner, cardinal voting method where each voter distributes a budget of points across the candidates.
+///
+/// Ballots whose scores add up to more than the budget are discarded. The candidate with the most points wins, or for multi-winner elections, the `seats` candidates with the most points are elected (with ties broken by the tie-breaker, and then in favor of the lowest candidate id).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Cumulative<W: Outcome> {
+    seats: usize,
+    budget: usize,
+    #[serde(skip)]
+    winner: PhantomData<W>,
+}
+
+/// A single-winner or multi-winner, cardinal voting method where each voter distributes a budget of voice credits across the candidates.
+///
+/// Giving a candidate `n` votes costs `n²` voice credits, and ballots whose costs add up to more than the budget are discarded. The candidate with the most votes wins, or for multi-winner elections, the `seats` candidates with the most votes are elected (with ties broken by the tie-breaker, and then in favor of the lowest candidate id).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Quadratic<W: Outcome> {
+    seats: usize,
+    budget: usize,
+    #[serde(skip)]
+    winner: PhantomData<W>,
+}
+
+impl Cumulative<SingleWinner> {
+    /// Creates a new instance of single-winner cumulative voting with the specified budget of points.
+    #[must_use]
+    pub const fn new(budget: usize) -> Self {
+        Self {
+            seats: 1,
+            budget,
+            winner: PhantomData,
+        }
+    }
+}
+
+impl Cumulative<MultiWinner> {
+    /// Creates a new instance of multi-winner cumulative voting with the specified number of seats and budget of points.
+    #[must_use]
+    pub const fn new(seats: usize, budget: usize) -> Self {
+        Self {
+            seats,
+            budget,
+            winner: PhantomData,
+        }
+    }
+}
+
+impl<W: Outcome> Cumulative<W> {
+    /// Whether the ballot's scores fit within the budget.
+    #[must_use]
+    pub fn is_valid(&self, ballot: &Cardinal) -> bool {
+        ballot.values().sum::<usize>() <= self.budget
+    }
+}
+
+impl Quadratic<SingleWinner> {
+    /// Creates a new instance of single-winner quadratic voting with the specified budget of voice credits.
+    #[must_use]
+    pub const fn new(budget: usize) -> Self {
+        Self {
+            seats: 1,
+            budget,
+            winner: PhantomData,
+        }
+    }
+}
+
+impl Quadratic<MultiWinner> {
+    /// Creates a new instance of multi-winner quadratic voting with the specified number of seats and budget of voice credits.
+    #[must_use]
+    pub const fn new(seats: usize, budget: usize) -> Self {
+        Self {
+            seats,
+            budget,
+            winner: PhantomData,
+        }
+    }
+}
+
+impl<W: Outcome> Quadratic<W> {
+    /// Whether the cost of the ballot's votes fits within the budget.
+    #[must_use]
+    pub fn is_valid(&self, ballot: &Cardinal) -> bool {
+        ballot.values().map(|votes| votes * votes).sum::<usize>() <= self.budget
+    }
+}
+
+impl Method for Cumulative<SingleWinner> {
+    type Ballot = Cardinal;
+    type Winner = SingleWinner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        let totals = totals(candidates, &profile, |b| self.is_valid(b));
+        single_winner(candidates, &totals)
+    }
+}
+
+impl Method for Cumulative<MultiWinner> {
+    type Ballot = Cardinal;
+    type Winner = MultiWinner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        self.outcome_with(candidates, profile, &mut Ties::none())
+    }
+    fn outcome_with(
+        &self,
+        candidates: &[Candidate],
+        profile: Profile<Self::Ballot>,
+        ties: &mut Ties,
+    ) -> Self::Winner {
+        let totals = totals(candidates, &profile, |b| self.is_valid(b));
+        multi_winner(candidates, &totals, self.seats, ties)
+    }
+}
+
+impl Describe for Cumulative<SingleWinner> {
+    fn describe(&self) -> Component {
+        Component::new("cumulative").param("budget", self.budget)
+    }
+}
+
+impl Describe for Cumulative<MultiWinner> {
+    fn describe(&self) -> Component {
+        Component::new("cumulative")
+            .param("budget", self.budget)
+            .param("seats", self.seats)
+    }
+}
+
+impl Method for Quadratic<SingleWinner> {
+    type Ballot = Cardinal;
+    type Winner = SingleWinner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        let totals = totals(candidates, &profile, |b| self.is_valid(b));
+        single_winner(candidates, &totals)
+    }
+}
+
+impl Method for Quadratic<MultiWinner> {
+    type Ballot = Cardinal;
+    type Winner = MultiWinner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        self.outcome_with(candidates, profile, &mut Ties::none())
+    }
+    fn outcome_with(
+        &self,
+        candidates: &[Candidate],
+        profile: Profile<Self::Ballot>,
+        ties: &mut Ties,
+    ) -> Self::Winner {
+        let totals = totals(candidates, &profile, |b| self.is_valid(b));
+        multi_winner(candidates, &totals, self.seats, ties)
+    }
+}
+
+impl Describe for Quadratic<SingleWinner> {
+    fn describe(&self) -> Component {
+        Component::new("quadratic").param("budget", self.budget)
+    }
+}
+
+impl Describe for Quadratic<MultiWinner> {
+    fn describe(&self) -> Component {
+        Component::new("quadratic")
+            .param("budget", self.budget)
+            .param("seats", self.seats)
+    }
+}
+
+/// The total score of each candidate over the valid ballots.
+fn totals(
+    candidates: &[Candidate],
+    profile: &Profile<Cardinal>,
+    is_valid: impl Fn(&Cardinal) -> bool,
+) -> Vec<usize> {
+    let mut totals = vec![0usize; candidates.len()];
+    for b in profile.iter().filter(|b| is_valid(b)) {
+        for (&c, &score) in b.iter() {
+            totals[c] += score;
+        }
+    }
+    totals
+}
+
+fn single_winner(candidates: &[Candidate], totals: &[usize]) -> SingleWinner {
+    let Some(max_total) = totals.iter().max().filter(|total| **total > 0) else {
+        return SingleWinner::none();
+    };
+    let winners: Vec<Id> = find_candidates_with_value(totals, max_total);
+    match winners.len() {
+        0 => SingleWinner::none(),
+        1 => SingleWinner::win(candidates, winners[0]),
+        _ => SingleWinner::tie(candidates, &winners),
+    }
+}
+
+fn multi_winner(
+    candidates: &[Candidate],
+    totals: &[usize],
+    seats: usize,
+    ties: &mut Ties,
+) -> MultiWinner {
+    let elected = most_votes(totals, seats, ties);
+    if elected.is_empty() {
+        return MultiWinner::none();
+    }
+    MultiWinner::seats(candidates, &elected)
+}
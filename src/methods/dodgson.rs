@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::{Component, Describe};
+use crate::core::{Candidate, Id, Method, Ordinal, Profile, SingleWinner};
+use crate::methods::{MethodError, SEARCH_LIMIT, pairwise_preferences};
+
+/// A single-winner, ranked, Condorcet-consistent voting method.
+///
+/// The Dodgson score of a candidate is the fewest swaps of adjacent candidates on the ballots needed to make them beat every other candidate pairwise, and the candidate with the lowest score wins. A candidate left off a ballot can only be swapped past the ranked candidates.
+///
+/// Computing Dodgson scores is NP-hard. They are found exactly by dynamic programming over the ballots, tracking how many more voters need to switch to the candidate against each opponent, which is fast for elections with few candidates and voters. The number of states grows with both, so past the candidate limit (6 by default), or when the search would visit more than a million states, the outcome falls back to Tideman's approximation, where a candidate's score is the sum of their pairwise defeat margins.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Dodgson {
+    limit: usize,
+}
+
+impl Dodgson {
+    /// Creates a new instance of Dodgson that solves elections with up to `limit` candidates exactly.
+    #[must_use]
+    pub const fn new(limit: usize) -> Self {
+        Self { limit }
+    }
+    /// Computes the Dodgson score of each candidate exactly, or `None` for candidates that no swaps make a Condorcet winner.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are more candidates than the limit, or the search visits too many states.
+    pub fn scores(
+        &self,
+        candidates: &[Candidate],
+        profile: &Profile<Ordinal>,
+    ) -> Result<Vec<Option<usize>>, MethodError> {
+        if candidates.len() > self.limit {
+            return Err(MethodError::TooManyCandidates {
+                found: candidates.len(),
+                limit: self.limit,
+            });
+        }
+        let d = pairwise_preferences(candidates, profile);
+        (0..candidates.len())
+            .map(|c| dodgson_score(&d, profile, c))
+            .collect()
+    }
+}
+
+impl Default for Dodgson {
+    fn default() -> Self {
+        Self::new(6)
+    }
+}
+
+impl Method for Dodgson {
+    type Ballot = Ordinal;
+    type Winner = SingleWinner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        let scores = self
+            .scores(candidates, &profile)
+            .unwrap_or_else(|_| tideman_scores(candidates, &profile));
+        let Some(min_score) = scores.iter().flatten().min() else {
+            return SingleWinner::none();
+        };
+        let winners: Vec<Id> = (0..candidates.len())
+            .filter(|&c| scores[c] == Some(*min_score))
+            .collect();
+        match winners.len() {
+            0 => SingleWinner::none(),
+            1 => SingleWinner::win(candidates, winners[0]),
+            _ => SingleWinner::tie(candidates, &winners),
+        }
+    }
+}
+
+impl Describe for Dodgson {
+    fn describe(&self) -> Component {
+        Component::new("dodgson").param("limit", self.limit)
+    }
+}
+
+/// Computes the Dodgson score of `c` exactly.
+fn dodgson_score(
+    d: &[Vec<usize>],
+    ballots: &[Ordinal],
+    c: Id,
+) -> Result<Option<usize>, MethodError> {
+    // The number of voters that still need to switch from preferring each opponent to preferring `c`.
+    let deficits: Vec<usize> = (0..d.len())
+        .map(|j| {
+            if j == c || d[c][j] > d[j][c] {
+                0
+            } else {
+                (d[j][c] - d[c][j]) / 2 + 1
+            }
+        })
+        .collect();
+
+    // The fewest swaps needed to reach each vector of remaining deficits.
+    let mut swaps: HashMap<Vec<usize>, usize> = HashMap::from([(deficits, 0)]);
+    let mut visited = 0;
+    for b in ballots {
+        // The candidates `c` can be swapped past, closest first.
+        let position = b.iter().position(|&other| other == c).unwrap_or(b.len());
+        let above: Vec<Id> = b[..position].iter().rev().copied().collect();
+        visited += swaps.len() * (above.len() + 1);
+        if visited > SEARCH_LIMIT {
+            return Err(MethodError::TooManyStates {
+                limit: SEARCH_LIMIT,
+            });
+        }
+        let mut next: HashMap<Vec<usize>, usize> = HashMap::with_capacity(swaps.len());
+        for (deficits, cost) in swaps {
+            let mut deficits = deficits;
+            for k in 0..=above.len() {
+                if k > 0 {
+                    let passed = above[k - 1];
+                    deficits[passed] = deficits[passed].saturating_sub(1);
+                }
+                let best = next.entry(deficits.clone()).or_insert(usize::MAX);
+                *best = (*best).min(cost + k);
+            }
+        }
+        swaps = next;
+    }
+    Ok(swaps.get(&vec![0; d.len()]).copied())
+}
+
+/// Tideman's approximation of the Dodgson (and Young) score of each candidate: the sum of the margins by which they lose pairwise.
+pub fn tideman_scores(candidates: &[Candidate], ballots: &[Ordinal]) -> Vec<Option<usize>> {
+    let d = pairwise_preferences(candidates, ballots);
+    (0..d.len())
+        .map(|c| Some((0..d.len()).map(|j| d[j][c].saturating_sub(d[c][j])).sum()))
+        .collect()
+}
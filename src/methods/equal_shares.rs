@@ -0,0 +1,254 @@
+use std::marker::PhantomData;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::{Component, Describe};
+use crate::core::{Ballot, Candidate, Cardinal, Id, Method, MultiWinner, Nominal, Profile, Ties};
+use crate::methods::phragmen::sequential_phragmen;
+use crate::methods::{as_f64, best_of};
+
+/// A multi-winner voting method (the Method of Equal Shares) for nominal or cardinal ballots.
+///
+/// Each voter is given an equal share of a budget of `seats` units, and each candidate costs one unit. Candidates are elected one at a time, each time electing the candidate that its supporters can afford while paying the lowest price per unit of utility (where an approval is worth one unit of utility, and a score is worth its value). Supporters pay in proportion to their utility for the candidate, except that those who cannot afford their share pay what they have left. Ties are broken by the tie-breaker, and then in favor of the lowest candidate id.
+///
+/// If some seats are left unfilled because no candidate is affordable, they are filled by the completion rule.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EqualShares<B: Ballot> {
+    seats: usize,
+    completion: Completion,
+    #[serde(skip)]
+    ballot: PhantomData<B>,
+}
+
+/// The rule used to fill the seats that the Method of Equal Shares leaves unfilled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Completion {
+    /// Leave the seats unfilled.
+    None,
+    /// Elect the candidates with the highest total utility.
+    Utilitarian,
+    /// Continue with Phragmén's sequential method, where every candidate with positive utility counts as approved, and each voter starts with a load equal to the money they spent.
+    Phragmen,
+    /// Repeatedly increase the total budget by one unit (the cost of a seat, shared equally by the voters) and rerun the method, keeping the largest committee that fits in the seats, then elect the candidates with the highest total utility.
+    ///
+    /// As every rerun counts the whole election again, the budget is increased at most once per candidate, after which the remaining seats are filled by total utility.
+    AddOne,
+}
+
+impl<B: Ballot> EqualShares<B> {
+    /// Creates a new instance of the Method of Equal Shares with the specified number of seats and completion rule.
+    #[must_use]
+    pub const fn new(seats: usize, completion: Completion) -> Self {
+        Self {
+            seats,
+            completion,
+            ballot: PhantomData,
+        }
+    }
+    /// Elects a committee from the utilities each voter has for each candidate.
+    fn committee(
+        &self,
+        candidates: &[Candidate],
+        utilities: &[Vec<(Id, f64)>],
+        ties: &mut Ties,
+    ) -> Vec<Id> {
+        let seats = self.seats.min(candidates.len());
+        let costs = vec![1.0; candidates.len()];
+        let (mut committee, budgets) = spend(&costs, as_f64(seats), utilities, ties);
+        match self.completion {
+            Completion::None | Completion::Utilitarian => {}
+            Completion::Phragmen => {
+                let approvals: Profile<Nominal> = utilities
+                    .iter()
+                    .map(|u| Nominal(u.iter().map(|(c, _)| *c).collect()))
+                    .collect();
+                // a seat costs one unit of money, and places one unit of load
+                let share = as_f64(seats) / as_f64(utilities.len());
+                let loads = budgets.iter().map(|budget| share - budget).collect();
+                committee =
+                    sequential_phragmen(candidates, &approvals, seats, committee, loads, ties);
+            }
+            Completion::AddOne => {
+                for budget in seats + 1..=seats + candidates.len() {
+                    if committee.len() >= seats {
+                        break;
+                    }
+                    let larger = equal_shares(&costs, as_f64(budget), utilities, ties);
+                    if larger.len() > seats {
+                        break;
+                    }
+                    committee = larger;
+                }
+            }
+        }
+        if self.completion != Completion::None {
+            let totals = utilities.iter().flatten().fold(
+                vec![0.0f64; candidates.len()],
+                |mut totals, (c, u)| {
+                    totals[*c] += u;
+                    totals
+                },
+            );
+            while committee.len() < seats {
+                let contenders = (0..candidates.len()).filter(|c| !committee.contains(c));
+                let Some(best) = best_of(contenders, |c| totals[c], ties) else {
+                    break;
+                };
+                committee.push(best);
+            }
+        }
+        committee
+    }
+}
+
+impl Method for EqualShares<Nominal> {
+    type Ballot = Nominal;
+    type Winner = MultiWinner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        self.outcome_with(candidates, profile, &mut Ties::none())
+    }
+    fn outcome_with(
+        &self,
+        candidates: &[Candidate],
+        profile: Profile<Self::Ballot>,
+        ties: &mut Ties,
+    ) -> Self::Winner {
+        let utilities: Vec<Vec<(Id, f64)>> = profile
+            .iter()
+            .map(|b| b.iter().map(|&c| (c, 1.0)).collect())
+            .collect();
+        let committee = self.committee(candidates, &utilities, ties);
+        if committee.is_empty() {
+            return MultiWinner::none();
+        }
+        MultiWinner::seats(candidates, &committee)
+    }
+}
+
+impl Method for EqualShares<Cardinal> {
+    type Ballot = Cardinal;
+    type Winner = MultiWinner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        self.outcome_with(candidates, profile, &mut Ties::none())
+    }
+    fn outcome_with(
+        &self,
+        candidates: &[Candidate],
+        profile: Profile<Self::Ballot>,
+        ties: &mut Ties,
+    ) -> Self::Winner {
+        let utilities: Vec<Vec<(Id, f64)>> = profile
+            .iter()
+            .map(|b| {
+                b.iter()
+                    .filter(|(_, score)| **score > 0)
+                    .map(|(&c, &score)| (c, as_f64(score)))
+                    .collect()
+            })
+            .collect();
+        let committee = self.committee(candidates, &utilities, ties);
+        if committee.is_empty() {
+            return MultiWinner::none();
+        }
+        MultiWinner::seats(candidates, &committee)
+    }
+}
+
+impl<B: Ballot> Describe for EqualShares<B> {
+    fn describe(&self) -> Component {
+        let completion = match self.completion {
+            Completion::None => "none",
+            Completion::Utilitarian => "utilitarian",
+            Completion::Phragmen => "phragmen",
+            Completion::AddOne => "add_one",
+        };
+        Component::new("equal_shares")
+            .param("seats", self.seats)
+            .param("completion", completion)
+    }
+}
+
+/// The tolerance used when comparing amounts of money, so that rounding errors do not make candidates unaffordable.
+const EPSILON: f64 = 1e-9;
+
+/// Runs the Method of Equal Shares (without completion) for candidates with the given costs and a total budget split equally between the voters. Voters only support the candidates they have positive utility for.
+pub fn equal_shares(
+    costs: &[f64],
+    budget: f64,
+    utilities: &[Vec<(Id, f64)>],
+    ties: &mut Ties,
+) -> Vec<Id> {
+    spend(costs, budget, utilities, ties).0
+}
+
+/// Runs the Method of Equal Shares, returning the committee and the money each voter has left.
+fn spend(
+    costs: &[f64],
+    budget: f64,
+    utilities: &[Vec<(Id, f64)>],
+    ties: &mut Ties,
+) -> (Vec<Id>, Vec<f64>) {
+    let mut committee: Vec<Id> = Vec::new();
+    if utilities.is_empty() {
+        return (committee, Vec::new());
+    }
+    let mut budgets = vec![budget / as_f64(utilities.len()); utilities.len()];
+    // The supporters of each candidate, with their utility for the candidate.
+    let supporters = utilities.iter().enumerate().fold(
+        vec![Vec::new(); costs.len()],
+        |mut supporters: Vec<Vec<(usize, f64)>>, (voter, u)| {
+            for &(c, utility) in u {
+                supporters[c].push((voter, utility));
+            }
+            supporters
+        },
+    );
+
+    loop {
+        let prices: Vec<Option<f64>> = (0..costs.len())
+            .map(|c| {
+                (!committee.contains(&c))
+                    .then(|| price(costs[c], &supporters[c], &budgets))
+                    .flatten()
+            })
+            .collect();
+        let affordable = (0..costs.len()).filter(|&c| prices[c].is_some());
+        let Some(elected) = best_of(affordable, |c| -prices[c].unwrap_or(f64::INFINITY), ties)
+        else {
+            break;
+        };
+        let rho = prices[elected].unwrap_or(0.0);
+        for &(voter, utility) in &supporters[elected] {
+            budgets[voter] = rho.mul_add(-utility, budgets[voter]).max(0.0);
+        }
+        committee.push(elected);
+    }
+    (committee, budgets)
+}
+
+/// The lowest price per unit of utility at which the supporters of a candidate can afford its cost, if they can afford it at all.
+fn price(cost: f64, supporters: &[(usize, f64)], budgets: &[f64]) -> Option<f64> {
+    let total: f64 = supporters.iter().map(|&(voter, _)| budgets[voter]).sum();
+    if supporters.is_empty() || total < cost - EPSILON {
+        return None;
+    }
+    // Supporters run out of money in order of their budget per unit of utility.
+    let mut order: Vec<(f64, f64)> = supporters
+        .iter()
+        .map(|&(voter, utility)| (budgets[voter], utility))
+        .collect();
+    order.sort_by(|a, b| (a.0 / a.1).total_cmp(&(b.0 / b.1)));
+    let mut remaining_cost = cost;
+    let mut remaining_utility: f64 = order.iter().map(|(_, utility)| utility).sum();
+    for (budget, utility) in order {
+        let rho = remaining_cost / remaining_utility;
+        if rho * utility <= budget + EPSILON {
+            return Some(rho);
+        }
+        remaining_cost -= budget;
+        remaining_utility -= utility;
+    }
+    None
+}
@@ -1,7 +1,9 @@
-use crate::core::{Candidate, Id, Method, Ordinal, Profile, SingleWinner};
+use crate::config::{Component, Describe};
+use crate::core::{Candidate, Id, Method, Ordinal, Profile, SingleWinner, Ties};
+use crate::methods::as_f64;
 
-/// A single-winner, ranked voting method. The candidate with the fewest votes is eliminated in each round, and votes for the eliminated candidate are redistributed to the next preference. This process continues until one candidate has a majority.
-#[derive(Debug, Clone, serde::Serialize)]
+/// A single-winner, ranked voting method. The candidate with the fewest votes is eliminated in each round, and votes for the eliminated candidate are redistributed to the next preference. This process continues until one candidate has a majority. Candidates tied for the fewest votes are all eliminated at once, unless a tie-breaker picks one of them.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct IRV;
 
 impl Method for IRV {
@@ -9,61 +11,105 @@ impl Method for IRV {
     type Winner = SingleWinner;
     #[inline]
     fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        self.outcome_with(candidates, profile, &mut Ties::none())
+    }
+    fn outcome_with(
+        &self,
+        candidates: &[Candidate],
+        profile: Profile<Self::Ballot>,
+        ties: &mut Ties,
+    ) -> Self::Winner {
         let majority = profile.len() / 2 + 1;
-        let mut remaining_ranking: Vec<Self::Ballot> = profile.iter().cloned().collect();
-        let mut remaining_candidates = candidates.len();
-
-        let first_place_counts = |profile: &Vec<Self::Ballot>| -> Vec<usize> {
-            profile
-                .iter()
-                .fold(vec![0; candidates.len()], |mut fpv, b| {
-                    if let Some(first_place_candidate) = b.first() {
-                        fpv[*first_place_candidate] += 1;
-                    }
-                    fpv
-                })
-        };
-
-        let mut fpc = first_place_counts(&remaining_ranking);
-        let mut max_first_place_votes: usize = *fpc.iter().max().unwrap();
-
-        while max_first_place_votes < majority {
-            // Find eliminated candidates.
-            let min_first_place_votes: usize = *fpc.iter().filter(|&&x| x > 0).min().unwrap();
-            let losers: Vec<Id> = fpc
-                .iter()
-                .enumerate()
-                .filter_map(|(i, &x)| (x == min_first_place_votes && x > 0).then_some(i))
-                .collect();
-            remaining_candidates -= losers.len();
-
-            // If all candidates have the same number of first-place votes, then break and tie.
-            if remaining_candidates == 0 {
-                break;
+        eliminate(candidates, &profile, ties, |ballots, remaining, ties| {
+            let fpc = first_place_counts(candidates, ballots);
+            ties.record(fpc.iter().copied().map(as_f64));
+            let max_first_place_votes = remaining.iter().map(|&c| fpc[c]).max().unwrap_or(0);
+            if max_first_place_votes >= majority || remaining.len() == 1 {
+                return Round::Elect(
+                    remaining
+                        .iter()
+                        .copied()
+                        .filter(|&c| fpc[c] == max_first_place_votes)
+                        .collect(),
+                );
             }
+            // Eliminate a candidate with the fewest first-place votes.
+            let min_first_place_votes = remaining.iter().map(|&c| fpc[c]).min().unwrap_or(0);
+            Round::EliminateOne(
+                remaining
+                    .iter()
+                    .copied()
+                    .filter(|&c| fpc[c] == min_first_place_votes)
+                    .collect(),
+            )
+        })
+    }
+}
 
-            // Reallocate votes.
-            for ballot in &mut remaining_ranking {
-                ballot.retain(|c| !losers.contains(c));
-            }
-            remaining_ranking.retain(|b| !b.is_empty());
+impl Describe for IRV {
+    fn describe(&self) -> Component {
+        Component::new("irv")
+    }
+}
 
-            // Recalculate the standings.
-            fpc = first_place_counts(&remaining_ranking);
-            max_first_place_votes = *fpc.iter().max().unwrap();
-        }
+/// The result of a single round of an elimination method.
+pub enum Round {
+    /// The round determined the winner(s), who tie if there are several.
+    Elect(Vec<Id>),
+    /// The round eliminated the given candidates.
+    Eliminate(Vec<Id>),
+    /// The round eliminated one of the given tied candidates, chosen by the tie-breaker (or all of them, if it cannot tell them apart).
+    EliminateOne(Vec<Id>),
+}
 
-        let winners: Vec<Id> = fpc
-            .iter()
-            .enumerate()
-            .filter(|&(_, &x)| x == max_first_place_votes)
-            .map(|(i, _)| i)
-            .collect();
+/// Runs an elimination method to completion.
+///
+/// Each round is given the ballots restricted to the remaining candidates (with exhausted ballots removed), the remaining candidates and the tie-breaker, and either elects the winner(s) or eliminates some candidates. If a round eliminates every remaining candidate (or none at all), the remaining candidates tie.
+pub fn eliminate(
+    candidates: &[Candidate],
+    profile: &Profile<Ordinal>,
+    ties: &mut Ties,
+    mut round: impl FnMut(&[Ordinal], &[Id], &mut Ties) -> Round,
+) -> SingleWinner {
+    let mut ballots: Vec<Ordinal> = profile.iter().cloned().collect();
+    let mut remaining: Vec<Id> = candidates.iter().map(Candidate::id).collect();
+    remaining.sort_unstable();
 
-        match winners.len() {
-            0 => SingleWinner::none(),
-            1 => SingleWinner::win(candidates, winners[0]),
-            _ => SingleWinner::tie(candidates, &winners),
+    let winners: Vec<Id> = loop {
+        if remaining.is_empty() {
+            break vec![];
+        }
+        let losers = match round(&ballots, &remaining, ties) {
+            Round::Elect(winners) => break winners,
+            Round::Eliminate(losers) => losers,
+            Round::EliminateOne(losers) => ties.worst(&losers),
+        };
+        if losers.is_empty() || remaining.iter().all(|c| losers.contains(c)) {
+            break remaining;
         }
+        // Reallocate votes.
+        remaining.retain(|c| !losers.contains(c));
+        for ballot in &mut ballots {
+            ballot.retain(|c| !losers.contains(c));
+        }
+        ballots.retain(|b| !b.is_empty());
+    };
+
+    match winners.len() {
+        0 => SingleWinner::none(),
+        1 => SingleWinner::win(candidates, winners[0]),
+        _ => SingleWinner::tie(candidates, &winners),
     }
 }
+
+/// Counts the first-place votes of each candidate.
+pub fn first_place_counts(candidates: &[Candidate], ballots: &[Ordinal]) -> Vec<usize> {
+    ballots
+        .iter()
+        .fold(vec![0; candidates.len()], |mut fpc, b| {
+            if let Some(first_place_candidate) = b.first() {
+                fpc[*first_place_candidate] += 1;
+            }
+            fpc
+        })
+}
@@ -0,0 +1,139 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::{Component, Describe};
+use crate::core::{Candidate, Id, Method, Ordinal, Profile, SingleWinner};
+use crate::methods::{MethodError, pairwise_preferences};
+
+/// A single-winner, ranked, Condorcet-consistent voting method (also known as Kemeny-Young).
+///
+/// The consensus ranking is the ranking that minimizes the total Kendall tau distance to the ballots, i.e. the number of pairwise disagreements between it and each ballot. The winner is the candidate at the top of the consensus ranking, and if several consensus rankings have different candidates at the top, they tie.
+///
+/// The consensus is found exactly by dynamic programming over subsets of candidates, which takes `O(2^n * n^2)` time. Past the candidate limit (10 by default, and at most [`Kemeny::MAX_LIMIT`]), the outcome silently falls back to a locally optimal ranking found by swapping adjacent candidates, starting from the Copeland ranking, so its winner may not be the Kemeny winner. Use [`Kemeny::consensus`] to get an error instead.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Kemeny {
+    limit: usize,
+}
+
+impl Kemeny {
+    /// The largest candidate limit, as the exact solver needs a table of `2^n` distances.
+    pub const MAX_LIMIT: usize = 20;
+    /// Creates a new instance of Kemeny that solves elections with up to `limit` candidates exactly. The limit is clamped to [`Kemeny::MAX_LIMIT`].
+    #[must_use]
+    pub const fn new(limit: usize) -> Self {
+        Self {
+            limit: if limit > Self::MAX_LIMIT {
+                Self::MAX_LIMIT
+            } else {
+                limit
+            },
+        }
+    }
+    /// The candidate limit
+    #[must_use]
+    pub const fn limit(&self) -> usize {
+        self.limit
+    }
+    /// Computes a consensus ranking exactly. If there are several, the lexicographically smallest is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are more candidates than the limit.
+    pub fn consensus(
+        &self,
+        candidates: &[Candidate],
+        profile: &Profile<Ordinal>,
+    ) -> Result<Vec<Id>, MethodError> {
+        let d = pairwise_preferences(candidates, profile);
+        let distances = self.distances(&d)?;
+        let mut ranking = Vec::with_capacity(d.len());
+        let mut placed = 0usize;
+        while let Some(next) = (0..d.len()).filter(|&c| placed & (1 << c) == 0).find(|&c| {
+            placement_cost(&d, c, placed) + distances[placed | (1 << c)] == distances[placed]
+        }) {
+            ranking.push(next);
+            placed |= 1 << next;
+        }
+        Ok(ranking)
+    }
+    /// Computes, for every set of candidates placed at the top of the ranking, the minimum distance contributed by ordering the remaining candidates below them.
+    fn distances(&self, d: &[Vec<usize>]) -> Result<Vec<usize>, MethodError> {
+        let n = d.len();
+        // A deserialized limit has not been clamped yet.
+        let limit = self.limit.min(Self::MAX_LIMIT);
+        if n > limit {
+            return Err(MethodError::TooManyCandidates { found: n, limit });
+        }
+        let full = (1usize << n) - 1;
+        let mut distances = vec![0; full + 1];
+        for placed in (0..full).rev() {
+            distances[placed] = (0..n)
+                .filter(|&c| placed & (1 << c) == 0)
+                .map(|c| placement_cost(d, c, placed) + distances[placed | (1 << c)])
+                .min()
+                .unwrap_or(0);
+        }
+        Ok(distances)
+    }
+}
+
+impl Default for Kemeny {
+    fn default() -> Self {
+        Self::new(10)
+    }
+}
+
+impl Method for Kemeny {
+    type Ballot = Ordinal;
+    type Winner = SingleWinner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        let d = pairwise_preferences(candidates, &profile);
+        let winners: Vec<Id> = self.distances(&d).map_or_else(
+            |_| local_consensus(&d).into_iter().take(1).collect(),
+            |distances| {
+                (0..d.len())
+                    .filter(|&c| placement_cost(&d, c, 0) + distances[1 << c] == distances[0])
+                    .collect()
+            },
+        );
+        match winners.len() {
+            0 => SingleWinner::none(),
+            1 => SingleWinner::win(candidates, winners[0]),
+            _ => SingleWinner::tie(candidates, &winners),
+        }
+    }
+}
+
+impl Describe for Kemeny {
+    fn describe(&self) -> Component {
+        Component::new("kemeny").param("limit", self.limit)
+    }
+}
+
+/// The number of pairwise disagreements caused by placing `c` directly below the `placed` candidates (and above all others).
+fn placement_cost(d: &[Vec<usize>], c: Id, placed: usize) -> usize {
+    (0..d.len())
+        .filter(|&other| other != c && placed & (1 << other) == 0)
+        .map(|other| d[other][c])
+        .sum()
+}
+
+/// Finds a locally optimal consensus ranking, in which no two adjacent candidates can be swapped to reduce the distance.
+fn local_consensus(d: &[Vec<usize>]) -> Vec<Id> {
+    let n = d.len();
+    let wins = |c: Id| (0..n).filter(|&other| d[c][other] > d[other][c]).count();
+    let mut ranking: Vec<Id> = (0..n).collect();
+    ranking.sort_by_key(|&c| std::cmp::Reverse(wins(c)));
+    let mut improved = true;
+    while improved {
+        improved = false;
+        for i in 1..n {
+            let (upper, lower) = (ranking[i - 1], ranking[i]);
+            if d[lower][upper] > d[upper][lower] {
+                ranking.swap(i - 1, i);
+                improved = true;
+            }
+        }
+    }
+    ranking
+}
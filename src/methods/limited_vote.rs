@@ -0,0 +1,130 @@
+use std::marker::PhantomData;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::{Component, Describe};
+use crate::core::{Ballot, Candidate, Method, MultiWinner, Nominal, Ordinal, Profile, Ties};
+use crate::methods::most_votes;
+
+/// A multi-winner, plurality-style voting method (limited voting) for ranked or nominal ballots.
+///
+/// Each voter casts up to `votes` votes, one per candidate, and the `seats` candidates with the most votes are elected. Limited voting gives voters fewer votes than seats: with one vote this is the single non-transferable vote (SNTV), while block voting (plurality-at-large), with as many votes as seats, is built with [`LimitedVote::block`].
+///
+/// Ranked ballots vote for their first `votes` ranked candidates. Nominal ballots vote for every approved candidate, and ballots approving more than `votes` candidates are spoiled. Ties for the last seat are broken by the tie-breaker, and then in favor of the lowest candidate id.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LimitedVote<B: Ballot> {
+    seats: usize,
+    votes: usize,
+    #[serde(skip)]
+    ballot: PhantomData<B>,
+}
+
+impl<B: Ballot> LimitedVote<B> {
+    /// Creates a new instance of limited voting with the specified number of seats and votes per voter.
+    ///
+    /// # Panics
+    ///
+    /// Panics unless there are between one and one fewer than the number of seats votes, as more would make this block voting (see [`LimitedVote::sntv`] and [`LimitedVote::block`]).
+    #[must_use]
+    pub fn new(seats: usize, votes: usize) -> Self {
+        assert!(
+            0 < votes && votes < seats,
+            "limited voting needs between 1 and {} votes, not {votes}",
+            seats.saturating_sub(1)
+        );
+        Self::with_votes(seats, votes)
+    }
+    const fn with_votes(seats: usize, votes: usize) -> Self {
+        Self {
+            seats,
+            votes,
+            ballot: PhantomData,
+        }
+    }
+    /// Creates a new instance of the single non-transferable vote with the specified number of seats.
+    #[must_use]
+    pub const fn sntv(seats: usize) -> Self {
+        Self::with_votes(seats, 1)
+    }
+    /// Creates a new instance of block voting with the specified number of seats.
+    #[must_use]
+    pub const fn block(seats: usize) -> Self {
+        Self::with_votes(seats, seats)
+    }
+    /// The number of votes per voter
+    #[must_use]
+    pub const fn votes(&self) -> usize {
+        self.votes
+    }
+    /// Elects the candidates with the most votes.
+    fn committee(
+        &self,
+        candidates: &[Candidate],
+        counts: &[usize],
+        ties: &mut Ties,
+    ) -> MultiWinner {
+        let elected = most_votes(counts, self.seats, ties);
+        if elected.is_empty() {
+            return MultiWinner::none();
+        }
+        MultiWinner::seats(candidates, &elected)
+    }
+}
+
+impl Method for LimitedVote<Ordinal> {
+    type Ballot = Ordinal;
+    type Winner = MultiWinner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        self.outcome_with(candidates, profile, &mut Ties::none())
+    }
+    fn outcome_with(
+        &self,
+        candidates: &[Candidate],
+        profile: Profile<Self::Ballot>,
+        ties: &mut Ties,
+    ) -> Self::Winner {
+        let mut counts = vec![0usize; candidates.len()];
+        for b in profile.iter() {
+            for &c in b.iter().take(self.votes) {
+                counts[c] += 1;
+            }
+        }
+        self.committee(candidates, &counts, ties)
+    }
+}
+
+impl Method for LimitedVote<Nominal> {
+    type Ballot = Nominal;
+    type Winner = MultiWinner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        self.outcome_with(candidates, profile, &mut Ties::none())
+    }
+    fn outcome_with(
+        &self,
+        candidates: &[Candidate],
+        profile: Profile<Self::Ballot>,
+        ties: &mut Ties,
+    ) -> Self::Winner {
+        let mut counts = vec![0usize; candidates.len()];
+        for b in profile.iter().filter(|b| b.len() <= self.votes) {
+            for &c in b.iter() {
+                counts[c] += 1;
+            }
+        }
+        self.committee(candidates, &counts, ties)
+    }
+}
+
+impl<B: Ballot> Describe for LimitedVote<B> {
+    fn describe(&self) -> Component {
+        match self.votes {
+            1 => Component::new("sntv").param("seats", self.seats),
+            votes if votes == self.seats => Component::new("block").param("seats", self.seats),
+            votes => Component::new("limited_vote")
+                .param("seats", self.seats)
+                .param("votes", votes),
+        }
+    }
+}
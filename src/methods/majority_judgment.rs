@@ -0,0 +1,54 @@
+use crate::config::{Component, Describe};
+use crate::core::{Candidate, Cardinal, Id, Method, Profile, SingleWinner};
+
+/// A single-winner, cardinal voting method. Each voter grades the candidates, and the candidate with the highest median grade wins.
+///
+/// Ties are broken by repeatedly removing one median grade from each tied candidate and comparing the new medians. The lower median is used when a candidate has an even number of grades, and candidates missing from a ballot receive the lowest grade (zero) from that voter.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct MajorityJudgment;
+
+impl Method for MajorityJudgment {
+    type Ballot = Cardinal;
+    type Winner = SingleWinner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        if profile.is_empty() {
+            return SingleWinner::none();
+        }
+        let medians: Vec<Vec<usize>> = (0..candidates.len())
+            .map(|c| {
+                let mut grades: Vec<usize> = profile
+                    .iter()
+                    .map(|b| b.get(&c).copied().unwrap_or(0))
+                    .collect();
+                grades.sort_unstable();
+                median_sequence(grades)
+            })
+            .collect();
+
+        let best = medians.iter().max().unwrap();
+        let winners: Vec<Id> = (0..candidates.len())
+            .filter(|&c| &medians[c] == best)
+            .collect();
+        match winners.len() {
+            0 => SingleWinner::none(),
+            1 => SingleWinner::win(candidates, winners[0]),
+            _ => SingleWinner::tie(candidates, &winners),
+        }
+    }
+}
+
+impl Describe for MajorityJudgment {
+    fn describe(&self) -> Component {
+        Component::new("majority_judgment")
+    }
+}
+
+/// The sequence of medians obtained by repeatedly removing the (lower) median from the sorted grades.
+fn median_sequence(mut grades: Vec<usize>) -> Vec<usize> {
+    let mut medians = Vec::with_capacity(grades.len());
+    while !grades.is_empty() {
+        medians.push(grades.remove((grades.len() - 1) / 2));
+    }
+    medians
+}
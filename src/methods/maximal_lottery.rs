@@ -0,0 +1,102 @@
+use crate::config::{Component, Describe};
+use crate::core::{Candidate, Lottery, Method, Ordinal, Profile};
+use crate::methods::{as_f64, pairwise_preferences};
+
+/// A single-winner, ranked, probabilistic voting method (maximal lotteries). The outcome is a lottery that is preferred by a majority (in expectation) to every other lottery.
+///
+/// This is an optimal mixed strategy of the symmetric zero-sum game whose payoff matrix is the pairwise margin matrix, found with the simplex method. The lottery puts all of its probability on the Condorcet winner when there is one, and is unique when the number of voters is odd. Otherwise, one of the maximal lotteries is returned.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct MaximalLottery;
+
+impl Method for MaximalLottery {
+    type Ballot = Ordinal;
+    type Winner = Lottery;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        if candidates.is_empty() || profile.is_empty() {
+            return Lottery::none();
+        }
+        let d = pairwise_preferences(candidates, &profile);
+        let margins: Vec<Vec<f64>> = (0..candidates.len())
+            .map(|i| {
+                (0..candidates.len())
+                    .map(|j| as_f64(d[i][j]) - as_f64(d[j][i]))
+                    .collect()
+            })
+            .collect();
+        Lottery::distribution(candidates, &optimal_strategy(&margins))
+    }
+}
+
+impl Describe for MaximalLottery {
+    fn describe(&self) -> Component {
+        Component::new("maximal_lottery")
+    }
+}
+
+/// The tolerance used when comparing values in the simplex tableau.
+const EPSILON: f64 = 1e-9;
+
+/// Finds an optimal mixed strategy of the symmetric zero-sum game with the given (skew-symmetric) payoff matrix.
+///
+/// The payoffs are shifted to be positive, so that the game can be solved as the linear program `max Σy` subject to `Ay ≤ 1, y ≥ 0`, whose normalized solution is an optimal strategy. Since the game is symmetric, it is optimal for both players. The program is solved with the simplex method, using Bland's rule to avoid cycling.
+fn optimal_strategy(payoffs: &[Vec<f64>]) -> Vec<f64> {
+    let n = payoffs.len();
+    let shift = 1.0
+        + payoffs
+            .iter()
+            .flatten()
+            .fold(0.0f64, |max, p| max.max(p.abs()));
+
+    // Each row is a constraint over the strategy and slack variables, followed by its bound.
+    let mut tableau: Vec<Vec<f64>> = payoffs
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            let mut constraint: Vec<f64> = row.iter().map(|p| p + shift).collect();
+            constraint.extend((0..n).map(|j| if i == j { 1.0 } else { 0.0 }));
+            constraint.push(1.0);
+            constraint
+        })
+        .collect();
+    let mut costs: Vec<f64> = (0..2 * n).map(|j| if j < n { 1.0 } else { 0.0 }).collect();
+    let mut basis: Vec<usize> = (n..2 * n).collect();
+
+    while let Some(entering) = (0..2 * n).find(|&j| costs[j] > EPSILON) {
+        let Some(pivot) = (0..n)
+            .filter(|&i| tableau[i][entering] > EPSILON)
+            .min_by(|&a, &b| {
+                let ratio_a = tableau[a][2 * n] / tableau[a][entering];
+                let ratio_b = tableau[b][2 * n] / tableau[b][entering];
+                ratio_a.total_cmp(&ratio_b).then(basis[a].cmp(&basis[b]))
+            })
+        else {
+            break;
+        };
+        let scale = tableau[pivot][entering];
+        for value in &mut tableau[pivot] {
+            *value /= scale;
+        }
+        let pivot_row = tableau[pivot].clone();
+        for (_, row) in tableau.iter_mut().enumerate().filter(|(i, _)| *i != pivot) {
+            let factor = row[entering];
+            for (value, p) in row.iter_mut().zip(&pivot_row) {
+                *value -= factor * p;
+            }
+        }
+        let factor = costs[entering];
+        for (cost, p) in costs.iter_mut().zip(&pivot_row) {
+            *cost -= factor * p;
+        }
+        basis[pivot] = entering;
+    }
+
+    let mut strategy = vec![0.0; n];
+    for (i, &variable) in basis.iter().enumerate() {
+        if variable < n {
+            strategy[variable] = tableau[i][2 * n].max(0.0);
+        }
+    }
+    let total: f64 = strategy.iter().sum();
+    strategy.iter().map(|y| y / total).collect()
+}
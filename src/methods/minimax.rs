@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::{Component, Describe};
+use crate::core::{Candidate, Id, Method, Ordinal, Profile, SingleWinner};
+use crate::methods::{find_candidates_with_value, pairwise_preferences};
+
+/// A single-winner, ranked voting method (also known as Simpson-Kramer). Each candidate is scored by their worst pairwise defeat, and the candidate whose worst defeat is the least severe wins.
+///
+/// The variant determines how the severity of a defeat is measured. `WinningVotes` and `Margins` are Condorcet-consistent, while `PairwiseOpposition` is not.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Minimax {
+    /// The number of voters that prefer the opponent, counted only when the opponent wins.
+    WinningVotes,
+    /// The difference between the number of voters that prefer the opponent and the number that prefer the candidate.
+    Margins,
+    /// The number of voters that prefer the opponent, whether or not the opponent wins.
+    PairwiseOpposition,
+}
+
+impl Method for Minimax {
+    type Ballot = Ordinal;
+    type Winner = SingleWinner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        let n = candidates.len();
+        let d = pairwise_preferences(candidates, &profile);
+        let severity = |opponent: Id, c: Id| -> isize {
+            let (against, support) = (d[opponent][c].cast_signed(), d[c][opponent].cast_signed());
+            match self {
+                Self::WinningVotes if against > support => against,
+                Self::WinningVotes => 0,
+                Self::Margins => against - support,
+                Self::PairwiseOpposition => against,
+            }
+        };
+        let scores: Vec<isize> = (0..n)
+            .map(|c| {
+                (0..n)
+                    .filter(|&opponent| opponent != c)
+                    .map(|opponent| severity(opponent, c))
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+        let Some(min_score) = scores.iter().min() else {
+            return SingleWinner::none();
+        };
+        let winners: Vec<Id> = find_candidates_with_value(&scores, min_score);
+        match winners.len() {
+            0 => SingleWinner::none(),
+            1 => SingleWinner::win(candidates, winners[0]),
+            _ => SingleWinner::tie(candidates, &winners),
+        }
+    }
+}
+
+impl Describe for Minimax {
+    fn describe(&self) -> Component {
+        let variant = match self {
+            Self::WinningVotes => "winning_votes",
+            Self::Margins => "margins",
+            Self::PairwiseOpposition => "pairwise_opposition",
+        };
+        Component::new("minimax").param("variant", variant)
+    }
+}
@@ -1,19 +1,94 @@
 //! A collection of `methods` that can be used to tabulate the results of an election.
+use thiserror::Error;
+
+use std::collections::BTreeSet;
+
+use crate::core::{Candidate, Id, Ordinal, Ties};
+
 mod approval;
+mod baldwin;
 mod borda;
+mod bottom_two_runoff;
+mod bucklin;
+mod chamberlin_courant;
+mod coombs;
+mod copeland;
+mod cumulative;
+mod dodgson;
+mod equal_shares;
 mod instant_runoff;
+mod kemeny;
+mod limited_vote;
+mod majority_judgment;
+mod maximal_lottery;
+mod minimax;
+mod monroe;
+mod nanson;
+mod participatory_budgeting;
+mod party_list;
+mod phragmen;
 mod plurality;
+mod positional;
+mod proportional_approval;
+mod proportional_lottery;
 mod random_dictator;
+mod ranked_pairs;
+mod schulze;
+mod score;
 mod single_transferable_vote;
+mod smith_restricted;
 mod star;
+mod tideman_alternative;
+mod two_round_runoff;
+mod young;
 
 pub use approval::Approval;
+pub use baldwin::Baldwin;
 pub use borda::Borda;
+pub use bottom_two_runoff::BottomTwoRunoff;
+pub use bucklin::Bucklin;
+pub use chamberlin_courant::ChamberlinCourant;
+pub use coombs::Coombs;
+pub use copeland::Copeland;
+pub use cumulative::{Cumulative, Quadratic};
+pub use dodgson::Dodgson;
+pub use equal_shares::{Completion, EqualShares};
 pub use instant_runoff::IRV;
+pub use kemeny::Kemeny;
+pub use limited_vote::LimitedVote;
+pub use majority_judgment::MajorityJudgment;
+pub use maximal_lottery::MaximalLottery;
+pub use minimax::Minimax;
+pub use monroe::Monroe;
+pub use nanson::Nanson;
+pub use participatory_budgeting::{EqualSharesBudgeting, GreedyBudgeting, PhragmenBudgeting};
+pub use party_list::{Apportionment, PartyList, Quota};
+pub use phragmen::Phragmen;
 pub use plurality::Plurality;
+pub use positional::Positional;
+pub use proportional_approval::{PAV, SequentialPAV, Solver};
+pub use proportional_lottery::ProportionalLottery;
 pub use random_dictator::RandomDictator;
-pub use single_transferable_vote::STV;
+pub use ranked_pairs::{RankedPairs, Strength};
+pub use schulze::Schulze;
+pub use score::Score;
+pub use single_transferable_vote::{STV, TransferRule};
+pub use smith_restricted::{DominantSet, SmithRestricted};
 pub use star::Star;
+pub use tideman_alternative::TidemanAlternative;
+pub use two_round_runoff::{SupplementaryVote, TwoRoundRunoff};
+pub use young::Young;
+
+#[derive(Debug, Error)]
+pub enum MethodError {
+    #[error("Too many candidates to solve exactly ({found} > {limit})")]
+    TooManyCandidates { found: usize, limit: usize },
+    #[error("Too many states to search exactly (more than {limit})")]
+    TooManyStates { limit: usize },
+}
+
+/// The most states that an exact search may visit before giving up, which bounds the searches whose state space grows with the number of voters, and the number of committees searched by the exact multi-winner solvers.
+pub(crate) const SEARCH_LIMIT: usize = 1_000_000;
 
 pub(crate) fn find_candidates_with_value<T: PartialEq>(slice: &[T], value: &T) -> Vec<usize> {
     let mut indices = Vec::with_capacity(slice.len()); // preallocate max possible size
@@ -25,9 +100,94 @@ pub(crate) fn find_candidates_with_value<T: PartialEq>(slice: &[T], value: &T) -
     indices
 }
 
+/// Converts a count into a float, for methods that work with fractional votes.
+#[allow(clippy::cast_precision_loss)]
+pub(crate) const fn as_f64(count: usize) -> f64 {
+    count as f64
+}
+
+/// The `seats` candidates with the most votes, where ties for the last seats are broken by the tie-breaker (and then in favor of the lowest candidate id). Candidates without votes are never elected.
+pub(crate) fn most_votes(counts: &[usize], seats: usize, ties: &mut Ties) -> Vec<Id> {
+    ties.record(counts.iter().copied().map(as_f64));
+    let contenders: Vec<Id> = (0..counts.len()).filter(|&c| counts[c] > 0).collect();
+    let mut elected = ties.rank(&contenders, |c| counts[c]).concat();
+    elected.truncate(seats);
+    elected
+}
+
+/// The contender with the highest value, where values within a rounding error of each other are tied, and ties are broken by the tie-breaker (and then in favor of the lowest candidate id).
+pub(crate) fn best_of(
+    contenders: impl IntoIterator<Item = Id>,
+    value: impl Fn(Id) -> f64,
+    ties: &mut Ties,
+) -> Option<Id> {
+    const EPSILON: f64 = 1e-9;
+    let values: Vec<(Id, f64)> = contenders.into_iter().map(|c| (c, value(c))).collect();
+    let max = values
+        .iter()
+        .map(|&(_, v)| v)
+        .fold(f64::NEG_INFINITY, f64::max);
+    let leaders: Vec<Id> = values
+        .into_iter()
+        .filter(|&(_, v)| v >= max - EPSILON)
+        .map(|(c, _)| c)
+        .collect();
+    ties.best(&leaders).first().copied()
+}
+
+/// Chooses between equally good committees. The tie-breaker repeatedly picks the best of the candidates that only some of the committees elect, keeping the committees that elect them, and the lexicographically smallest of the committees it cannot tell apart is elected.
+pub(crate) fn choose_committee(mut committees: Vec<Vec<Id>>, ties: &mut Ties) -> Vec<Id> {
+    loop {
+        let contested: Vec<Id> = committees
+            .iter()
+            .flatten()
+            .copied()
+            .filter(|c| !committees.iter().all(|committee| committee.contains(c)))
+            .collect::<BTreeSet<Id>>()
+            .into_iter()
+            .collect();
+        let best = ties.best(&contested);
+        if best.len() == contested.len() {
+            break;
+        }
+        let remaining = committees.len();
+        committees.retain(|committee| best.iter().any(|c| committee.contains(c)));
+        if committees.len() == remaining {
+            break;
+        }
+    }
+    committees.into_iter().min().unwrap_or_default()
+}
+
+/// Computes the pairwise preference matrix of a profile, where `d[a][b]` is the number of voters that strictly prefer `a` over `b`.
+///
+/// Ballots may be truncated: every ranked candidate is preferred over every unranked candidate, and unranked candidates are tied with each other.
+pub(crate) fn pairwise_preferences(
+    candidates: &[Candidate],
+    ballots: &[Ordinal],
+) -> Vec<Vec<usize>> {
+    let n = candidates.len();
+    ballots.iter().fold(vec![vec![0; n]; n], |mut d, b| {
+        let mut ranked = vec![false; n];
+        for (i, &winner) in b.iter().enumerate() {
+            ranked[winner] = true;
+            for &loser in &b[i + 1..] {
+                d[winner][loser] += 1;
+            }
+        }
+        for &winner in b.iter() {
+            for loser in (0..n).filter(|&loser| !ranked[loser]) {
+                d[winner][loser] += 1;
+            }
+        }
+        d
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::{methods::*, *};
+    use rand::{SeedableRng, rngs::StdRng};
     use std::collections::{BTreeMap, BTreeSet};
 
     fn candidates() -> Vec<Candidate> {
@@ -61,6 +221,56 @@ mod tests {
         .into()
     }
 
+    fn many_candidates(n: usize) -> Vec<Candidate> {
+        const NAMES: [&str; 8] = ["A", "B", "C", "D", "E", "F", "G", "H"];
+        (0..n)
+            .map(|i| Candidate::new(i, NAMES[i], None, None))
+            .collect()
+    }
+
+    fn numbered_candidates(n: usize) -> Vec<Candidate> {
+        (0..n)
+            .map(|i| Candidate::new(i, &format!("C{i}"), None, None))
+            .collect()
+    }
+
+    fn party_candidates() -> Vec<Candidate> {
+        const LISTS: [(&str, &[&str]); 4] = [
+            ("A", &["A1", "A2", "A3", "A4", "A5"]),
+            ("B", &["B1", "B2", "B3", "B4"]),
+            ("C", &["C1", "C2"]),
+            ("D", &["D1", "D2"]),
+        ];
+        LISTS
+            .iter()
+            .flat_map(|(party, names)| names.iter().map(move |name| (*party, *name)))
+            .enumerate()
+            .map(|(id, (party, name))| Candidate::new(id, name, Some(party), None))
+            .collect()
+    }
+
+    /// First preferences for parties A, B, C, and D in the ratio 100 : 80 : 30 : 20.
+    fn party_ballots() -> Profile<Ordinal> {
+        repeated_ballots(&[(100, &[0]), (80, &[5]), (30, &[9]), (20, &[11])])
+    }
+
+    fn repeated_ballots(ballots: &[(usize, &[usize])]) -> Profile<Ordinal> {
+        ballots
+            .iter()
+            .flat_map(|&(count, ranking)| (0..count).map(|_| Ordinal(ranking.to_vec())))
+            .collect()
+    }
+
+    /// The classic Tennessee capital election, where Memphis (A) wins plurality, Knoxville (D) wins IRV, and Nashville (B) is the Condorcet winner.
+    fn tennessee_ballots() -> Profile<Ordinal> {
+        repeated_ballots(&[
+            (42, &[0, 1, 2, 3]),
+            (26, &[1, 2, 3, 0]),
+            (15, &[2, 3, 1, 0]),
+            (17, &[3, 2, 1, 0]),
+        ])
+    }
+
     fn cardinal_ballots() -> Profile<Cardinal> {
         vec![
             Cardinal(BTreeMap::from([(0, 4), (1, 3), (2, 4)])),
@@ -77,7 +287,8 @@ mod tests {
         let candidates = candidates();
         let ballots: Profile<Ordinal> = ordinal_ballots();
         let outcome = RandomDictator.outcome(&candidates, ballots);
-        assert_eq!(outcome, SingleWinner::win(&candidates, 0));
+        let probabilities = [1.0 / 3.0, 1.0 / 6.0, 1.0 / 2.0];
+        assert_eq!(outcome, Lottery::distribution(&candidates, &probabilities));
     }
 
     #[test]
@@ -104,6 +315,16 @@ mod tests {
         assert_eq!(outcome, SingleWinner::tie(&candidates, &[0, 1, 2]));
     }
 
+    #[test]
+    fn borda_truncated_outcome() {
+        let candidates = candidates();
+        // Truncated ballots give their unranked candidates no points, exactly as the positional Borda count.
+        let ballots = repeated_ballots(&[(2, &[0]), (1, &[1, 2, 0]), (1, &[2, 1])]);
+        let outcome = Borda.outcome(&candidates, ballots.clone());
+        assert_eq!(outcome, Positional::borda(3).outcome(&candidates, ballots));
+        assert_eq!(outcome, SingleWinner::win(&candidates, 0));
+    }
+
     #[test]
     fn star_outcome() {
         let candidates = candidates();
@@ -127,4 +348,884 @@ mod tests {
         let outcome = STV::new(2).outcome(&candidates, ballots);
         assert_eq!(outcome, MultiWinner::seats(&candidates, &[0, 2]));
     }
+
+    #[test]
+    fn schulze_outcome() {
+        let candidates = candidates();
+        let ballots = ordinal_ballots();
+        let outcome = Schulze.outcome(&candidates, ballots);
+        assert_eq!(outcome, SingleWinner::tie(&candidates, &[0, 1, 2]));
+    }
+
+    #[test]
+    fn schulze_beatpath_outcome() {
+        let candidates = many_candidates(5);
+        let ballots = repeated_ballots(&[
+            (5, &[0, 2, 1, 4, 3]),
+            (5, &[0, 3, 4, 2, 1]),
+            (8, &[1, 4, 3, 0, 2]),
+            (3, &[2, 0, 1, 4, 3]),
+            (7, &[2, 0, 4, 1, 3]),
+            (2, &[2, 1, 0, 3, 4]),
+            (7, &[3, 2, 4, 1, 0]),
+            (8, &[4, 1, 0, 3, 2]),
+        ]);
+        let outcome = Schulze.outcome(&candidates, ballots);
+        assert_eq!(outcome, SingleWinner::win(&candidates, 4));
+    }
+
+    #[test]
+    fn schulze_truncated_outcome() {
+        let candidates = candidates();
+        let ballots = repeated_ballots(&[(2, &[0]), (2, &[1, 2]), (1, &[2])]);
+        let outcome = Schulze.outcome(&candidates, ballots);
+        assert_eq!(outcome, SingleWinner::win(&candidates, 1));
+    }
+
+    #[test]
+    fn ranked_pairs_outcome() {
+        let candidates = candidates();
+        let ballots = ordinal_ballots();
+        let outcome = RankedPairs::default().outcome(&candidates, ballots);
+        assert_eq!(outcome, SingleWinner::tie(&candidates, &[0, 1, 2]));
+    }
+
+    #[test]
+    fn ranked_pairs_strength_outcome() {
+        let candidates = candidates();
+        // A beats B (9-6), B beats C (8-7), and C beats A (7-5).
+        let ballots = repeated_ballots(&[
+            (1, &[0]),
+            (4, &[1]),
+            (1, &[2]),
+            (3, &[0, 1, 2]),
+            (1, &[0, 2, 1]),
+            (1, &[1, 2, 0]),
+            (4, &[2, 0, 1]),
+            (1, &[2, 1, 0]),
+        ]);
+        let margins = RankedPairs::new(Strength::Margins).outcome(&candidates, ballots.clone());
+        assert_eq!(margins, SingleWinner::win(&candidates, 2));
+        let winning_votes = RankedPairs::new(Strength::WinningVotes).outcome(&candidates, ballots);
+        assert_eq!(winning_votes, SingleWinner::win(&candidates, 0));
+    }
+
+    #[test]
+    fn ranked_pairs_tiebreak_outcome() {
+        let candidates = candidates();
+        let ballots = repeated_ballots(&[(1, &[0, 1, 2]), (1, &[1, 2, 0]), (1, &[2, 0, 1])]);
+        let outcome = RankedPairs::default().outcome(&candidates, ballots.clone());
+        assert_eq!(outcome, SingleWinner::win(&candidates, 0));
+        let outcome = RankedPairs::default()
+            .break_ties(TieBreaker::Order(vec![2, 1, 0]))
+            .outcome(&candidates, ballots);
+        assert_eq!(outcome, SingleWinner::win(&candidates, 1));
+    }
+
+    #[test]
+    fn copeland_outcome() {
+        let candidates = candidates();
+        let ballots = ordinal_ballots();
+        let outcome = Copeland::default().outcome(&candidates, ballots);
+        assert_eq!(outcome, SingleWinner::tie(&candidates, &[0, 1, 2]));
+    }
+
+    #[test]
+    fn copeland_tie_score_outcome() {
+        let candidates = many_candidates(4);
+        // A ties with B and C, while B beats C and D.
+        let ballots = repeated_ballots(&[(1, &[0, 1, 2, 3]), (1, &[1, 2, 0, 3])]);
+        let copeland = Copeland::default().outcome(&candidates, ballots.clone());
+        assert_eq!(copeland, SingleWinner::win(&candidates, 1));
+        let llull = Copeland::llull().outcome(&candidates, ballots);
+        assert_eq!(llull, SingleWinner::tie(&candidates, &[0, 1]));
+    }
+
+    #[test]
+    fn minimax_outcome() {
+        let candidates = candidates();
+        // A beats B (9-6), B beats C (8-7), and C beats A (7-5).
+        let ballots = repeated_ballots(&[
+            (1, &[0]),
+            (4, &[1]),
+            (1, &[2]),
+            (3, &[0, 1, 2]),
+            (1, &[0, 2, 1]),
+            (1, &[1, 2, 0]),
+            (4, &[2, 0, 1]),
+            (1, &[2, 1, 0]),
+        ]);
+        let winning_votes = Minimax::WinningVotes.outcome(&candidates, ballots.clone());
+        assert_eq!(winning_votes, SingleWinner::win(&candidates, 0));
+        let margins = Minimax::Margins.outcome(&candidates, ballots.clone());
+        assert_eq!(margins, SingleWinner::win(&candidates, 2));
+        let opposition = Minimax::PairwiseOpposition.outcome(&candidates, ballots);
+        assert_eq!(opposition, SingleWinner::win(&candidates, 0));
+    }
+
+    #[test]
+    fn kemeny_outcome() {
+        let candidates = candidates();
+        let ballots = ordinal_ballots();
+        let outcome = Kemeny::default().outcome(&candidates, ballots);
+        assert_eq!(outcome, SingleWinner::tie(&candidates, &[0, 1, 2]));
+    }
+
+    #[test]
+    fn kemeny_recovers_mallows_center() {
+        let candidates = many_candidates(5);
+        let mallows = preferences::Mallows::new(vec![3, 0, 4, 1, 2], 0.8);
+        let mut rng = StdRng::seed_from_u64(0);
+        let ballots: Profile<Ordinal> = mallows.sample(&candidates, 1_000, &mut rng);
+        let consensus = Kemeny::default().consensus(&candidates, &ballots).unwrap();
+        assert_eq!(consensus, vec![3, 0, 4, 1, 2]);
+        let outcome = Kemeny::new(4).outcome(&candidates, ballots);
+        assert_eq!(outcome, SingleWinner::win(&candidates, 3));
+    }
+
+    #[test]
+    fn kemeny_candidate_limit() {
+        let candidates = candidates();
+        let ballots = ordinal_ballots();
+        assert!(Kemeny::new(2).consensus(&candidates, &ballots).is_err());
+        // The exact solver is never asked for a table of 2^64 distances.
+        assert_eq!(Kemeny::new(64).limit(), Kemeny::MAX_LIMIT);
+        let names: Vec<String> = (0..64).map(|i| format!("C{i}")).collect();
+        let candidates: Vec<Candidate> = names
+            .iter()
+            .enumerate()
+            .map(|(id, name)| Candidate::new(id, name, None, None))
+            .collect();
+        let ballots: Profile<Ordinal> = vec![Ordinal((0..64).collect())].into();
+        assert!(matches!(
+            Kemeny::new(64).consensus(&candidates, &ballots),
+            Err(MethodError::TooManyCandidates {
+                found: 64,
+                limit: 20
+            })
+        ));
+        let outcome = Kemeny::new(64).outcome(&candidates, ballots);
+        assert_eq!(outcome, SingleWinner::win(&candidates, 0));
+    }
+
+    #[test]
+    fn instant_runoff_tennessee_outcome() {
+        let candidates = many_candidates(4);
+        let outcome = IRV.outcome(&candidates, tennessee_ballots());
+        assert_eq!(outcome, SingleWinner::win(&candidates, 3));
+    }
+
+    #[test]
+    fn coombs_outcome() {
+        let candidates = many_candidates(4);
+        let outcome = Coombs.outcome(&candidates, tennessee_ballots());
+        assert_eq!(outcome, SingleWinner::win(&candidates, 1));
+    }
+
+    #[test]
+    fn baldwin_outcome() {
+        let candidates = many_candidates(4);
+        let outcome = Baldwin.outcome(&candidates, tennessee_ballots());
+        assert_eq!(outcome, SingleWinner::win(&candidates, 1));
+        let candidates = self::candidates();
+        let outcome = Baldwin.outcome(&candidates, ordinal_ballots());
+        assert_eq!(outcome, SingleWinner::tie(&candidates, &[0, 1, 2]));
+    }
+
+    #[test]
+    fn nanson_outcome() {
+        let candidates = many_candidates(4);
+        let outcome = Nanson.outcome(&candidates, tennessee_ballots());
+        assert_eq!(outcome, SingleWinner::win(&candidates, 1));
+        let candidates = self::candidates();
+        let outcome = Nanson.outcome(&candidates, ordinal_ballots());
+        assert_eq!(outcome, SingleWinner::tie(&candidates, &[0, 1, 2]));
+    }
+
+    #[test]
+    fn bucklin_outcome() {
+        let candidates = candidates();
+        // Both A and B reach a majority once second-place votes are counted.
+        let outcome = Bucklin::MostVotes.outcome(&candidates, ordinal_ballots());
+        assert_eq!(outcome, SingleWinner::win(&candidates, 1));
+        let outcome = Bucklin::Tie.outcome(&candidates, ordinal_ballots());
+        assert_eq!(outcome, SingleWinner::tie(&candidates, &[0, 1]));
+    }
+
+    #[test]
+    fn score_outcome() {
+        let candidates = candidates();
+        let outcome = Score::Total.outcome(&candidates, cardinal_ballots());
+        assert_eq!(outcome, SingleWinner::win(&candidates, 1));
+        let outcome = Score::Average { quorum: 0 }.outcome(&candidates, cardinal_ballots());
+        assert_eq!(outcome, SingleWinner::win(&candidates, 1));
+    }
+
+    #[test]
+    fn score_quorum_outcome() {
+        let candidates = candidates();
+        let ballots: Profile<Cardinal> = vec![
+            Cardinal(BTreeMap::from([(0, 5)])),
+            Cardinal(BTreeMap::from([(1, 4), (2, 3)])),
+            Cardinal(BTreeMap::from([(1, 4), (2, 3)])),
+        ]
+        .into();
+        let outcome = Score::Average { quorum: 1 }.outcome(&candidates, ballots.clone());
+        assert_eq!(outcome, SingleWinner::win(&candidates, 0));
+        let outcome = Score::Average { quorum: 2 }.outcome(&candidates, ballots);
+        assert_eq!(outcome, SingleWinner::win(&candidates, 1));
+    }
+
+    #[test]
+    fn majority_judgment_outcome() {
+        let candidates = candidates();
+        let outcome = MajorityJudgment.outcome(&candidates, cardinal_ballots());
+        assert_eq!(outcome, SingleWinner::win(&candidates, 0));
+    }
+
+    #[test]
+    fn majority_judgment_tiebreak_outcome() {
+        let candidates = candidates();
+        // A and B share a median grade of 3, but B's next median is higher.
+        let ballots: Profile<Cardinal> = vec![
+            Cardinal(BTreeMap::from([(0, 3), (1, 3), (2, 0)])),
+            Cardinal(BTreeMap::from([(0, 4), (1, 3), (2, 0)])),
+            Cardinal(BTreeMap::from([(0, 1), (1, 2), (2, 5)])),
+        ]
+        .into();
+        let outcome = MajorityJudgment.outcome(&candidates, ballots);
+        assert_eq!(outcome, SingleWinner::win(&candidates, 1));
+    }
+
+    #[test]
+    fn two_round_runoff_outcome() {
+        let candidates = candidates();
+        let outcome = TwoRoundRunoff.outcome(&candidates, ordinal_ballots());
+        assert_eq!(outcome, SingleWinner::tie(&candidates, &[0, 2]));
+        let candidates = many_candidates(4);
+        let outcome = TwoRoundRunoff.outcome(&candidates, tennessee_ballots());
+        assert_eq!(outcome, SingleWinner::win(&candidates, 1));
+    }
+
+    #[test]
+    fn supplementary_vote_outcome() {
+        let candidates = many_candidates(4);
+        // Supporters of C and D rank B third, so their ballots are exhausted in the runoff.
+        let outcome = SupplementaryVote.outcome(&candidates, tennessee_ballots());
+        assert_eq!(outcome, SingleWinner::win(&candidates, 0));
+    }
+
+    #[test]
+    fn positional_outcome() {
+        let candidates = candidates();
+        let outcome = Positional::plurality().outcome(&candidates, ordinal_ballots());
+        assert_eq!(outcome, SingleWinner::win(&candidates, 2));
+        let outcome = Positional::borda(3).outcome(&candidates, ordinal_ballots());
+        assert_eq!(outcome, SingleWinner::tie(&candidates, &[0, 1, 2]));
+        let outcome = Positional::dowdall(3).outcome(&candidates, ordinal_ballots());
+        assert_eq!(outcome, SingleWinner::win(&candidates, 2));
+        let outcome = Positional::anti_plurality(3).outcome(&candidates, ordinal_ballots());
+        assert_eq!(outcome, SingleWinner::win(&candidates, 1));
+        let outcome = Positional::k_approval(2).outcome(&candidates, ordinal_ballots());
+        assert_eq!(outcome, SingleWinner::win(&candidates, 1));
+    }
+
+    #[test]
+    fn party_list_outcome() {
+        let candidates = party_candidates();
+        let outcome = PartyList::dhondt(8).outcome(&candidates, party_ballots());
+        assert_eq!(
+            outcome,
+            MultiWinner::seats(&candidates, &[0, 1, 2, 3, 5, 6, 7, 9])
+        );
+        let outcome = PartyList::sainte_lague(8).outcome(&candidates, party_ballots());
+        assert_eq!(
+            outcome,
+            MultiWinner::seats(&candidates, &[0, 1, 2, 5, 6, 7, 9, 11])
+        );
+        let outcome = PartyList::sainte_lague(7).outcome(&candidates, party_ballots());
+        assert_eq!(
+            outcome,
+            MultiWinner::seats(&candidates, &[0, 1, 2, 5, 6, 9, 11])
+        );
+        let outcome = PartyList::new(7, Apportionment::ModifiedSainteLague)
+            .outcome(&candidates, party_ballots());
+        assert_eq!(
+            outcome,
+            MultiWinner::seats(&candidates, &[0, 1, 2, 5, 6, 7, 9])
+        );
+    }
+
+    #[test]
+    fn party_list_largest_remainder_outcome() {
+        let candidates = party_candidates();
+        let outcome = PartyList::hamilton(8, Quota::Hare).outcome(&candidates, party_ballots());
+        assert_eq!(
+            outcome,
+            MultiWinner::seats(&candidates, &[0, 1, 2, 5, 6, 7, 9, 11])
+        );
+        let outcome = PartyList::hamilton(8, Quota::Droop).outcome(&candidates, party_ballots());
+        assert_eq!(
+            outcome,
+            MultiWinner::seats(&candidates, &[0, 1, 2, 3, 5, 6, 7, 9])
+        );
+    }
+
+    /// Six voters approve of A, B, and C, while four approve only of D.
+    fn proportional_ballots() -> Profile<Nominal> {
+        (0..10)
+            .map(|i| match i {
+                0..6 => Nominal(BTreeSet::from([0, 1, 2])),
+                _ => Nominal(BTreeSet::from([3])),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn proportional_approval_outcome() {
+        let candidates = candidates();
+        let outcome = PAV::exact(2).outcome(&candidates, nominal_ballots());
+        assert_eq!(outcome, MultiWinner::seats(&candidates, &[1, 2]));
+        let candidates = many_candidates(4);
+        let outcome = PAV::exact(3).outcome(&candidates, proportional_ballots());
+        assert_eq!(outcome, MultiWinner::seats(&candidates, &[0, 1, 3]));
+        let outcome = PAV::local_search(3).outcome(&candidates, proportional_ballots());
+        assert_eq!(outcome, MultiWinner::seats(&candidates, &[0, 1, 3]));
+
+        // C(40, 20) committees are too many to search, so the exact solver falls back to local search.
+        let candidates = numbered_candidates(40);
+        let outcome = PAV::exact(20).outcome(&candidates, proportional_ballots());
+        assert_eq!(
+            outcome,
+            PAV::local_search(20).outcome(&candidates, proportional_ballots())
+        );
+    }
+
+    #[test]
+    fn sequential_proportional_approval_outcome() {
+        let candidates = many_candidates(4);
+        let outcome = SequentialPAV::new(3).outcome(&candidates, proportional_ballots());
+        assert_eq!(outcome, MultiWinner::seats(&candidates, &[0, 1, 3]));
+    }
+
+    #[test]
+    fn phragmen_outcome() {
+        let candidates = many_candidates(4);
+        let outcome = Phragmen::new(3).outcome(&candidates, proportional_ballots());
+        assert_eq!(outcome, MultiWinner::seats(&candidates, &[0, 1, 3]));
+        let candidates = self::candidates();
+        let outcome = Phragmen::new(3).outcome(&candidates, nominal_ballots());
+        assert_eq!(outcome, MultiWinner::seats(&candidates, &[0, 1, 2]));
+    }
+
+    #[test]
+    fn equal_shares_outcome() {
+        let candidates = many_candidates(4);
+        let outcome = EqualShares::<Nominal>::new(3, Completion::None)
+            .outcome(&candidates, proportional_ballots());
+        assert_eq!(outcome, MultiWinner::seats(&candidates, &[0, 3]));
+        for completion in [
+            Completion::Utilitarian,
+            Completion::Phragmen,
+            Completion::AddOne,
+        ] {
+            let outcome = EqualShares::<Nominal>::new(3, completion)
+                .outcome(&candidates, proportional_ballots());
+            assert_eq!(outcome, MultiWinner::seats(&candidates, &[0, 1, 3]));
+        }
+    }
+
+    #[test]
+    fn equal_shares_phragmen_completion() {
+        // Six voters approve of A, B, and C, and five approve of D and E. Equal Shares elects A, B, and D, spending 1/3 of each of the six and 1/5 of each of the five.
+        let candidates = many_candidates(5);
+        let ballots: Profile<Nominal> = (0..11)
+            .map(|i| match i {
+                0..6 => Nominal(BTreeSet::from([0, 1, 2])),
+                _ => Nominal(BTreeSet::from([3, 4])),
+            })
+            .collect();
+        let outcome = EqualShares::<Nominal>::new(4, Completion::Utilitarian)
+            .outcome(&candidates, ballots.clone());
+        assert_eq!(outcome, MultiWinner::seats(&candidates, &[0, 1, 2, 3]));
+        // Starting from the money spent, C would load the six with 1/2 each, and E would load the five with 2/5 each.
+        let outcome =
+            EqualShares::<Nominal>::new(4, Completion::Phragmen).outcome(&candidates, ballots);
+        assert_eq!(outcome, MultiWinner::seats(&candidates, &[0, 1, 3, 4]));
+    }
+
+    #[test]
+    fn equal_shares_cardinal_outcome() {
+        let candidates = many_candidates(4);
+        let ballots: Profile<Cardinal> = (0..10)
+            .map(|i| match i {
+                0..6 => Cardinal(BTreeMap::from([(0, 2), (1, 1), (2, 1)])),
+                _ => Cardinal(BTreeMap::from([(3, 3)])),
+            })
+            .collect();
+        let outcome =
+            EqualShares::<Cardinal>::new(3, Completion::None).outcome(&candidates, ballots.clone());
+        assert_eq!(outcome, MultiWinner::seats(&candidates, &[0, 3]));
+        let outcome =
+            EqualShares::<Cardinal>::new(3, Completion::Utilitarian).outcome(&candidates, ballots);
+        assert_eq!(outcome, MultiWinner::seats(&candidates, &[0, 1, 3]));
+    }
+
+    /// Five voters rank A > B > C > D, while one ranks D > C > B > A.
+    fn representation_ballots() -> Profile<Ordinal> {
+        repeated_ballots(&[(5, &[0, 1, 2, 3]), (1, &[3, 2, 1, 0])])
+    }
+
+    #[test]
+    fn chamberlin_courant_outcome() {
+        let candidates = many_candidates(4);
+        for solver in [Solver::Exact, Solver::Approximate] {
+            let outcome =
+                ChamberlinCourant::new(2, solver).outcome(&candidates, representation_ballots());
+            assert_eq!(outcome, MultiWinner::seats(&candidates, &[0, 3]));
+        }
+        let candidates = numbered_candidates(40);
+        let exact = ChamberlinCourant::new(20, Solver::Exact);
+        let approximate = ChamberlinCourant::new(20, Solver::Approximate);
+        assert_eq!(
+            exact.outcome(&candidates, representation_ballots()),
+            approximate.outcome(&candidates, representation_ballots())
+        );
+    }
+
+    #[test]
+    fn monroe_outcome() {
+        let candidates = many_candidates(4);
+        // A can only represent half of the voters, so B represents the rest.
+        for solver in [Solver::Exact, Solver::Approximate] {
+            let outcome = Monroe::new(2, solver).outcome(&candidates, representation_ballots());
+            assert_eq!(outcome, MultiWinner::seats(&candidates, &[0, 1]));
+        }
+        let candidates = numbered_candidates(40);
+        let exact = Monroe::new(20, Solver::Exact);
+        let approximate = Monroe::new(20, Solver::Approximate);
+        assert_eq!(
+            exact.outcome(&candidates, representation_ballots()),
+            approximate.outcome(&candidates, representation_ballots())
+        );
+    }
+
+    #[test]
+    fn single_transferable_vote_transfers() {
+        // Oranges, Pears, Chocolate, Strawberries, Hamburgers, from the worked example of Wikipedia's article on STV.
+        let candidates = many_candidates(5);
+        let ballots = repeated_ballots(&[
+            (4, &[0]),
+            (2, &[1, 0]),
+            (8, &[2, 3]),
+            (4, &[2, 4]),
+            (1, &[3]),
+            (1, &[4]),
+        ]);
+        // Chocolate's surplus elects Strawberries, and Pears' exclusion elects Oranges.
+        for rule in [
+            TransferRule::WeightedInclusiveGregory,
+            TransferRule::Scottish,
+            TransferRule::Meek,
+        ] {
+            for quota in [Quota::Droop, Quota::Hare] {
+                let outcome = STV::with_rule(3, rule)
+                    .quota(quota)
+                    .outcome(&candidates, Profile::clone(&ballots));
+                assert_eq!(outcome, MultiWinner::seats(&candidates, &[0, 2, 3]));
+            }
+        }
+    }
+
+    #[test]
+    fn meek_quota() {
+        let candidates = many_candidates(4);
+        let ballots = repeated_ballots(&[(2, &[1, 0, 3, 2]), (5, &[3, 0, 2, 1])]);
+        // The quota is 7/3, so D keeps 7/15 of each vote and passes 8/3 to A, who overtakes B and reaches the quota.
+        let outcome = STV::meek(2).outcome(&candidates, Profile::clone(&ballots));
+        assert_eq!(outcome, MultiWinner::seats(&candidates, &[0, 3]));
+        // With the rounded quota of 3, D only passes 2 to A, who ties B and is excluded for having had fewer votes.
+        let outcome = STV::new(2).outcome(&candidates, ballots);
+        assert_eq!(outcome, MultiWinner::seats(&candidates, &[1, 3]));
+    }
+
+    #[test]
+    fn limited_vote_outcome() {
+        let candidates = many_candidates(4);
+        let outcome =
+            LimitedVote::<Ordinal>::sntv(2).outcome(&candidates, representation_ballots());
+        assert_eq!(outcome, MultiWinner::seats(&candidates, &[0, 3]));
+        let outcome =
+            LimitedVote::<Ordinal>::block(2).outcome(&candidates, representation_ballots());
+        assert_eq!(outcome, MultiWinner::seats(&candidates, &[0, 1]));
+        let outcome =
+            LimitedVote::<Ordinal>::new(3, 2).outcome(&candidates, representation_ballots());
+        assert_eq!(outcome, MultiWinner::seats(&candidates, &[0, 1, 2]));
+        assert_eq!(LimitedVote::<Ordinal>::new(3, 2).votes(), 2);
+        assert_eq!(LimitedVote::<Ordinal>::block(2).votes(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "limited voting needs between 1 and 1 votes, not 2")]
+    fn limited_vote_too_many_votes() {
+        // Limited voting has fewer votes than seats.
+        let _ = LimitedVote::<Ordinal>::new(2, 2);
+    }
+
+    #[test]
+    fn limited_vote_nominal_outcome() {
+        let candidates = candidates();
+        let outcome = LimitedVote::<Nominal>::block(2).outcome(&candidates, nominal_ballots());
+        assert_eq!(outcome, MultiWinner::seats(&candidates, &[0, 1]));
+        // The ballot approving both A and B is spoiled.
+        let outcome = LimitedVote::<Nominal>::sntv(2).outcome(&candidates, nominal_ballots());
+        assert_eq!(outcome, MultiWinner::seats(&candidates, &[1, 2]));
+    }
+
+    #[test]
+    fn cumulative_outcome() {
+        let candidates = candidates();
+        // Only the third and fourth ballots fit in the budget.
+        let outcome = Cumulative::<SingleWinner>::new(5).outcome(&candidates, cardinal_ballots());
+        assert_eq!(outcome, SingleWinner::win(&candidates, 1));
+        let outcome = Cumulative::<MultiWinner>::new(2, 5).outcome(&candidates, cardinal_ballots());
+        assert_eq!(outcome, MultiWinner::seats(&candidates, &[1, 2]));
+    }
+
+    #[test]
+    fn quadratic_outcome() {
+        let candidates = candidates();
+        // Only the second and fourth ballots fit in the budget.
+        let outcome = Quadratic::<SingleWinner>::new(21).outcome(&candidates, cardinal_ballots());
+        assert_eq!(outcome, SingleWinner::win(&candidates, 0));
+        let outcome = Quadratic::<MultiWinner>::new(2, 21).outcome(&candidates, cardinal_ballots());
+        assert_eq!(outcome, MultiWinner::seats(&candidates, &[0, 1]));
+    }
+
+    #[test]
+    fn proportional_lottery_outcome() {
+        let candidates = candidates();
+        let outcome = ProportionalLottery.outcome(&candidates, nominal_ballots());
+        assert_eq!(
+            outcome,
+            Lottery::distribution(&candidates, &[0.25, 0.5, 0.25])
+        );
+        assert_eq!(outcome.winners(), vec!["A", "B", "C"]);
+    }
+
+    #[test]
+    fn maximal_lottery_outcome() {
+        // The Condorcet winner wins with certainty.
+        let candidates = many_candidates(4);
+        let outcome = MaximalLottery.outcome(&candidates, tennessee_ballots());
+        assert!((outcome.probability(1) - 1.0).abs() < 1e-9);
+        // A Condorcet cycle is a game of rock-paper-scissors.
+        let candidates = self::candidates();
+        let outcome = MaximalLottery.outcome(&candidates, cycle_ballots());
+        for c in 0..3 {
+            assert!((outcome.probability(c) - 1.0 / 3.0).abs() < 1e-9);
+        }
+        let utility = outcome.expected_utility(|c| if c.id() == 0 { 1.0 } else { 0.0 });
+        assert!((utility - 1.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn smith_restricted_outcome() {
+        // Nashville is the Condorcet winner, which IRV alone does not elect.
+        let candidates = many_candidates(4);
+        let outcome = SmithRestricted::new(IRV).outcome(&candidates, tennessee_ballots());
+        assert_eq!(outcome, SingleWinner::win(&candidates, 1));
+        let outcome = SmithRestricted::new(Plurality).outcome(&candidates, tennessee_ballots());
+        assert_eq!(outcome, SingleWinner::win(&candidates, 1));
+        // A is ranked last by everyone, so A has no pairwise defeats once removed from the ballots, and must not be given to Minimax.
+        let ballots =
+            repeated_ballots(&[(1, &[1, 2, 3, 0]), (1, &[2, 3, 1, 0]), (1, &[3, 1, 2, 0])]);
+        let outcome =
+            SmithRestricted::new(Minimax::WinningVotes).outcome(&candidates, ballots.clone());
+        assert_eq!(outcome, SingleWinner::tie(&candidates, &[1, 2, 3]));
+        let outcome = SmithRestricted::new(Minimax::WinningVotes)
+            .break_ties(TieBreaker::Order(vec![0, 3]))
+            .outcome(&candidates, ballots);
+        assert_eq!(outcome, SingleWinner::win(&candidates, 3));
+    }
+
+    #[test]
+    fn schwartz_restricted_outcome() {
+        // A ties B and B ties C, but A beats C, so C is in the Smith set but not the Schwartz set.
+        let candidates = candidates();
+        let ballots = repeated_ballots(&[(1, &[0, 2, 1]), (1, &[1, 0, 2])]);
+        let outcome = SmithRestricted::new(Borda).outcome(&candidates, ballots.clone());
+        assert_eq!(outcome, SingleWinner::win(&candidates, 0));
+        let outcome = SmithRestricted::schwartz(Borda).outcome(&candidates, ballots);
+        assert_eq!(outcome, SingleWinner::tie(&candidates, &[0, 1]));
+    }
+
+    #[test]
+    fn tideman_alternative_outcome() {
+        let candidates = many_candidates(4);
+        let outcome = TidemanAlternative.outcome(&candidates, tennessee_ballots());
+        assert_eq!(outcome, SingleWinner::win(&candidates, 1));
+        let candidates = self::candidates();
+        let outcome = TidemanAlternative.outcome(&candidates, cycle_ballots());
+        assert_eq!(outcome, SingleWinner::tie(&candidates, &[0, 1, 2]));
+    }
+
+    #[test]
+    fn bottom_two_runoff_outcome() {
+        let candidates = many_candidates(4);
+        let outcome = BottomTwoRunoff.outcome(&candidates, tennessee_ballots());
+        assert_eq!(outcome, SingleWinner::win(&candidates, 1));
+    }
+
+    /// A Condorcet cycle: A > B > C > A.
+    fn cycle_ballots() -> Profile<Ordinal> {
+        repeated_ballots(&[(1, &[0, 1, 2]), (1, &[1, 2, 0]), (1, &[2, 0, 1])])
+    }
+
+    #[test]
+    fn dodgson_outcome() {
+        let candidates = many_candidates(4);
+        let outcome = Dodgson::default().outcome(&candidates, tennessee_ballots());
+        assert_eq!(outcome, SingleWinner::win(&candidates, 1));
+        // Past the limit, Tideman's approximation still finds the Condorcet winner.
+        let outcome = Dodgson::new(2).outcome(&candidates, tennessee_ballots());
+        assert_eq!(outcome, SingleWinner::win(&candidates, 1));
+        // Each candidate needs a single swap to beat the candidate that beats them.
+        let candidates = self::candidates();
+        let scores = Dodgson::default().scores(&candidates, &cycle_ballots());
+        assert_eq!(scores.unwrap(), vec![Some(1), Some(1), Some(1)]);
+    }
+
+    #[test]
+    fn young_outcome() {
+        let candidates = many_candidates(4);
+        let outcome = Young::default().outcome(&candidates, tennessee_ballots());
+        assert_eq!(outcome, SingleWinner::win(&candidates, 1));
+        // Past the limit, both fall back to Tideman's approximation.
+        let outcome = Young::new(2).outcome(&candidates, tennessee_ballots());
+        assert_eq!(outcome, SingleWinner::win(&candidates, 1));
+        // Removing a single voter leaves each candidate tied with one of the others.
+        let candidates = self::candidates();
+        let scores = Young::default().scores(&candidates, &cycle_ballots());
+        assert_eq!(scores.unwrap(), vec![Some(2), Some(2), Some(2)]);
+    }
+
+    #[test]
+    fn dodgson_young_large_profile() {
+        // The exact searches grow with the number of voters, so they give up rather than run for ages.
+        let candidates = many_candidates(6);
+        let mut rng = StdRng::seed_from_u64(0);
+        let ballots: Profile<Ordinal> = preferences::Impartial.sample(&candidates, 5_000, &mut rng);
+        assert!(matches!(
+            Dodgson::default().scores(&candidates, &ballots),
+            Err(MethodError::TooManyStates { .. })
+        ));
+        assert!(matches!(
+            Young::default().scores(&candidates, &ballots),
+            Err(MethodError::TooManyStates { .. })
+        ));
+        let dodgson = Dodgson::default().outcome(&candidates, ballots.clone());
+        assert_eq!(
+            dodgson,
+            Dodgson::new(0).outcome(&candidates, ballots.clone())
+        );
+        assert_eq!(Young::default().outcome(&candidates, ballots), dodgson);
+    }
+
+    #[test]
+    fn greedy_budgeting_outcome() {
+        let candidates = many_candidates(4);
+        let outcome = GreedyBudgeting::<Nominal>::new(vec![1.0; 4], 3.0)
+            .outcome(&candidates, proportional_ballots());
+        assert_eq!(outcome, MultiWinner::seats(&candidates, &[0, 1, 2]));
+        // A costs too much to leave room for C.
+        let outcome = GreedyBudgeting::<Nominal>::new(vec![2.0, 1.0, 1.0, 1.0], 3.0)
+            .outcome(&candidates, proportional_ballots());
+        assert_eq!(outcome, MultiWinner::seats(&candidates, &[0, 1]));
+    }
+
+    #[test]
+    fn equal_shares_budgeting_outcome() {
+        let candidates = many_candidates(4);
+        // The supporters of D can afford it with their share of the budget.
+        let outcome = EqualSharesBudgeting::<Nominal>::new(vec![1.0; 4], 3.0)
+            .without_completion()
+            .outcome(&candidates, proportional_ballots());
+        assert_eq!(outcome, MultiWinner::seats(&candidates, &[0, 3]));
+        let outcome = EqualSharesBudgeting::<Nominal>::new(vec![1.0; 4], 3.0)
+            .outcome(&candidates, proportional_ballots());
+        assert_eq!(outcome, MultiWinner::seats(&candidates, &[0, 1, 3]));
+    }
+
+    #[test]
+    fn phragmen_budgeting_outcome() {
+        let candidates = many_candidates(4);
+        let outcome =
+            PhragmenBudgeting::new(vec![1.0; 4], 3.0).outcome(&candidates, proportional_ballots());
+        assert_eq!(outcome, MultiWinner::seats(&candidates, &[0, 1, 3]));
+        // Nothing fits in the budget.
+        let outcome =
+            PhragmenBudgeting::new(vec![4.0; 4], 3.0).outcome(&candidates, proportional_ballots());
+        assert_eq!(outcome, MultiWinner::none());
+    }
+
+    #[test]
+    fn tie_breaker_final_outcome() {
+        let candidates = candidates();
+        let ballots = ordinal_ballots();
+        let outcome = Borda
+            .break_ties(TieBreaker::None)
+            .outcome(&candidates, ballots.clone());
+        assert_eq!(outcome, SingleWinner::tie(&candidates, &[0, 1, 2]));
+        let outcome = Borda
+            .break_ties(TieBreaker::Order(vec![1, 2, 0]))
+            .outcome(&candidates, ballots.clone());
+        assert_eq!(outcome, SingleWinner::win(&candidates, 1));
+        // Candidates missing from the order come after those in it.
+        let outcome = Borda
+            .break_ties(TieBreaker::Order(vec![2]))
+            .outcome(&candidates, ballots.clone());
+        assert_eq!(outcome, SingleWinner::win(&candidates, 2));
+        // The Borda counts are tied as well, and a single round has no previous rounds to look back on.
+        for tie_breaker in [
+            TieBreaker::Borda,
+            TieBreaker::Forwards,
+            TieBreaker::Backwards,
+        ] {
+            let outcome = Borda
+                .break_ties(tie_breaker)
+                .outcome(&candidates, ballots.clone());
+            assert_eq!(outcome, SingleWinner::tie(&candidates, &[0, 1, 2]));
+        }
+        let random = Borda.break_ties(TieBreaker::Random(7));
+        let outcome = random.outcome(&candidates, ballots.clone());
+        assert!(matches!(outcome, SingleWinner::Win(_)));
+        assert_eq!(random.outcome(&candidates, ballots), outcome);
+    }
+
+    #[test]
+    fn tie_breaker_instant_runoff_outcome() {
+        let candidates = candidates();
+        // A and C tie in the final round, but C had more votes in the first.
+        let ballots = ordinal_ballots();
+        for tie_breaker in [TieBreaker::Forwards, TieBreaker::Backwards] {
+            let outcome = IRV
+                .break_ties(tie_breaker)
+                .outcome(&candidates, ballots.clone());
+            assert_eq!(outcome, SingleWinner::win(&candidates, 2));
+        }
+        let outcome = IRV
+            .break_ties(TieBreaker::Order(vec![0]))
+            .outcome(&candidates, ballots);
+        assert_eq!(outcome, SingleWinner::win(&candidates, 0));
+
+        // B and C tie for elimination, and whoever survives wins.
+        let ballots = repeated_ballots(&[(4, &[0]), (3, &[1, 2]), (3, &[2, 1])]);
+        let outcome = IRV.outcome(&candidates, ballots.clone());
+        assert_eq!(outcome, SingleWinner::win(&candidates, 0));
+        let outcome = IRV
+            .break_ties(TieBreaker::Backwards)
+            .outcome(&candidates, ballots.clone());
+        assert_eq!(outcome, SingleWinner::win(&candidates, 0));
+        let outcome = IRV
+            .break_ties(TieBreaker::Order(vec![1]))
+            .outcome(&candidates, ballots.clone());
+        assert_eq!(outcome, SingleWinner::win(&candidates, 1));
+        let outcome = IRV
+            .break_ties(TieBreaker::Order(vec![2]))
+            .outcome(&candidates, ballots.clone());
+        assert_eq!(outcome, SingleWinner::win(&candidates, 2));
+
+        // The tie-breaker reaches the method restricted to the Smith set.
+        let outcome = SmithRestricted::new(IRV)
+            .break_ties(TieBreaker::Order(vec![2]))
+            .outcome(&candidates, ballots);
+        assert_eq!(outcome, SingleWinner::win(&candidates, 2));
+    }
+
+    #[test]
+    fn tie_breaker_runoff_outcome() {
+        let candidates = candidates();
+        // B and C tie for second place, so both advance unless the tie is broken.
+        let ballots = repeated_ballots(&[(4, &[0]), (3, &[1, 2]), (3, &[2, 1])]);
+        let outcome = TwoRoundRunoff.outcome(&candidates, ballots.clone());
+        assert_eq!(outcome, SingleWinner::win(&candidates, 0));
+        let outcome = TwoRoundRunoff
+            .break_ties(TieBreaker::Order(vec![1]))
+            .outcome(&candidates, ballots.clone());
+        assert_eq!(outcome, SingleWinner::win(&candidates, 1));
+        let outcome = TwoRoundRunoff
+            .break_ties(TieBreaker::Order(vec![2]))
+            .outcome(&candidates, ballots);
+        assert_eq!(outcome, SingleWinner::win(&candidates, 2));
+    }
+
+    #[test]
+    fn tie_breaker_single_transferable_vote_outcome() {
+        let candidates = candidates();
+        // B and C tie for exclusion, which otherwise falls on the highest candidate id.
+        let ballots = repeated_ballots(&[(4, &[0]), (3, &[1, 2]), (3, &[2, 1])]);
+        let outcome = STV::new(1).outcome(&candidates, ballots.clone());
+        assert_eq!(outcome, MultiWinner::seats(&candidates, &[1]));
+        let outcome = STV::new(1)
+            .break_ties(TieBreaker::Order(vec![2]))
+            .outcome(&candidates, ballots);
+        assert_eq!(outcome, MultiWinner::seats(&candidates, &[2]));
+    }
+
+    /// Asserts that a method elects A and B when every candidate is as popular as the others, and B and C when C and then B are favored by the tie-breaker.
+    fn assert_committees<M: Method<Winner = MultiWinner>>(method: M, ballots: Profile<M::Ballot>) {
+        let candidates = candidates();
+        let outcome = method.outcome(&candidates, ballots.clone());
+        assert_eq!(outcome, MultiWinner::seats(&candidates, &[0, 1]));
+        let outcome = method
+            .break_ties(TieBreaker::Order(vec![2, 1]))
+            .outcome(&candidates, ballots);
+        assert_eq!(outcome, MultiWinner::seats(&candidates, &[1, 2]));
+    }
+
+    #[test]
+    fn tie_breaker_multi_winner_outcome() {
+        let nominal: Profile<Nominal> = (0..3).map(|c| Nominal(BTreeSet::from([c]))).collect();
+        assert_committees(LimitedVote::<Nominal>::sntv(2), nominal.clone());
+        assert_committees(SequentialPAV::new(2), nominal.clone());
+        assert_committees(PAV::exact(2), nominal.clone());
+        assert_committees(PAV::local_search(2), nominal.clone());
+        assert_committees(Phragmen::new(2), nominal.clone());
+        let mes = EqualShares::<Nominal>::new(2, Completion::Utilitarian);
+        assert_committees(mes, nominal.clone());
+        assert_committees(PhragmenBudgeting::new(vec![1.0; 3], 2.0), nominal.clone());
+        assert_committees(GreedyBudgeting::<Nominal>::new(vec![1.0; 3], 2.0), nominal);
+        let cardinal: Profile<Cardinal> =
+            (0..3).map(|c| Cardinal(BTreeMap::from([(c, 1)]))).collect();
+        assert_committees(Cumulative::<MultiWinner>::new(2, 1), cardinal);
+        for solver in [Solver::Exact, Solver::Approximate] {
+            assert_committees(ChamberlinCourant::new(2, solver), cycle_ballots());
+        }
+        assert_committees(Monroe::new(2, Solver::Exact), cycle_ballots());
+
+        // Parties A and B tie for the only seat.
+        let candidates = party_candidates();
+        let ballots = repeated_ballots(&[(1, &[0]), (1, &[5])]);
+        let outcome = PartyList::dhondt(1).outcome(&candidates, ballots.clone());
+        assert_eq!(outcome, MultiWinner::seats(&candidates, &[0]));
+        let outcome = PartyList::dhondt(1)
+            .break_ties(TieBreaker::Order(vec![5]))
+            .outcome(&candidates, ballots);
+        assert_eq!(outcome, MultiWinner::seats(&candidates, &[5]));
+    }
+
+    #[test]
+    fn tie_breaker_bucklin_outcome() {
+        let candidates = candidates();
+        // A and B both reach a majority in the second round, where B has more votes, but A had more in the first.
+        let outcome = Bucklin::Tie
+            .break_ties(TieBreaker::Forwards)
+            .outcome(&candidates, ordinal_ballots());
+        assert_eq!(outcome, SingleWinner::win(&candidates, 0));
+        let outcome = Bucklin::Tie
+            .break_ties(TieBreaker::Backwards)
+            .outcome(&candidates, ordinal_ballots());
+        assert_eq!(outcome, SingleWinner::win(&candidates, 1));
+    }
 }
@@ -0,0 +1,207 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::{Component, Describe};
+use crate::core::{Candidate, Id, Method, MultiWinner, Ordinal, Profile, Ties};
+use crate::methods::chamberlin_courant::misrepresentation;
+use crate::methods::proportional_approval::{check_committees, for_each_committee};
+use crate::methods::{MethodError, Solver, as_f64, best_of, choose_committee};
+
+/// A multi-winner, ranked voting method that elects the committee that best represents the voters, subject to every committee member representing an equal share of the voters.
+///
+/// Each voter is assigned to a committee member, with every member assigned either `floor(n / k)` or `ceil(n / k)` voters, and the committee and assignment minimize the total Borda misrepresentation: the number of candidates each voter ranks above their representative. Candidates left off a ballot are considered ranked last.
+///
+/// The exact solver searches every committee (solving the assignment for each as a min-cost flow), which is only feasible for small elections (when there are more than a million committees, it falls back to the approximate solver), and breaks ties between optimal committees with the tie-breaker, and then in favor of the lexicographically smallest committee. The approximate solver greedily elects the candidate whose share of the voters, chosen from those not yet assigned, is the least misrepresented, breaking ties with the tie-breaker.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Monroe {
+    seats: usize,
+    solver: Solver,
+}
+
+impl Monroe {
+    /// Creates a new instance of Monroe with the specified number of seats and solver.
+    #[must_use]
+    pub const fn new(seats: usize, solver: Solver) -> Self {
+        Self { seats, solver }
+    }
+}
+
+impl Method for Monroe {
+    type Ballot = Ordinal;
+    type Winner = MultiWinner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        self.outcome_with(candidates, profile, &mut Ties::none())
+    }
+    fn outcome_with(
+        &self,
+        candidates: &[Candidate],
+        profile: Profile<Self::Ballot>,
+        ties: &mut Ties,
+    ) -> Self::Winner {
+        let seats = self.seats.min(candidates.len());
+        let misrepresentation = misrepresentation(candidates, &profile);
+        let committee = match self.solver {
+            Solver::Exact => exact(&misrepresentation, candidates.len(), seats, ties)
+                .unwrap_or_else(|_| greedy(&misrepresentation, candidates.len(), seats, ties)),
+            Solver::Approximate => greedy(&misrepresentation, candidates.len(), seats, ties),
+        };
+        if committee.is_empty() {
+            return MultiWinner::none();
+        }
+        MultiWinner::seats(candidates, &committee)
+    }
+}
+
+impl Describe for Monroe {
+    fn describe(&self) -> Component {
+        Component::new("monroe")
+            .param("seats", self.seats)
+            .param("solver", self.solver.name())
+    }
+}
+
+/// Finds the least misrepresenting committees by searching every committee.
+fn exact(
+    misrepresentation: &[Vec<usize>],
+    candidates: usize,
+    seats: usize,
+    ties: &mut Ties,
+) -> Result<Vec<Id>, MethodError> {
+    check_committees(candidates, seats)?;
+    let mut best: Option<(usize, Vec<Vec<Id>>)> = None;
+    for_each_committee(candidates, seats, |committee| {
+        let cost = assignment_cost(misrepresentation, committee);
+        match &mut best {
+            Some((best, optimal)) if cost == *best => optimal.push(committee.to_vec()),
+            Some((best, _)) if cost > *best => {}
+            _ => best = Some((cost, vec![committee.to_vec()])),
+        }
+    });
+    Ok(best
+        .map(|(_, optimal)| choose_committee(optimal, ties))
+        .unwrap_or_default())
+}
+
+/// Elects a committee one member at a time, each time electing the candidate whose share of the unassigned voters is the least misrepresented.
+fn greedy(
+    misrepresentation: &[Vec<usize>],
+    candidates: usize,
+    seats: usize,
+    ties: &mut Ties,
+) -> Vec<Id> {
+    let voters = misrepresentation.len();
+    let mut unassigned: Vec<usize> = (0..voters).collect();
+    let mut committee: Vec<Id> = Vec::with_capacity(seats);
+    while committee.len() < seats {
+        // The first `voters % seats` members represent one extra voter.
+        let share = voters / seats + usize::from(committee.len() < voters % seats);
+        let represented = |c: Id| {
+            let mut voters = unassigned.clone();
+            voters.sort_by_key(|&v| misrepresentation[v][c]);
+            voters.truncate(share);
+            voters
+        };
+        let cost = |voters: &[usize], c: Id| -> usize {
+            voters.iter().map(|&v| misrepresentation[v][c]).sum()
+        };
+        let contenders = (0..candidates).filter(|c| !committee.contains(c));
+        let share_cost = |c: Id| -as_f64(cost(&represented(c), c));
+        let Some(next) = best_of(contenders, share_cost, ties) else {
+            break;
+        };
+        let represented = represented(next);
+        unassigned.retain(|v| !represented.contains(v));
+        committee.push(next);
+    }
+    committee
+}
+
+/// Computes the minimum misrepresentation of assigning every voter to a committee member, with each member representing an equal share of the voters.
+fn assignment_cost(misrepresentation: &[Vec<usize>], committee: &[Id]) -> usize {
+    let (voters, seats) = (misrepresentation.len(), committee.len());
+    if voters == 0 || seats == 0 {
+        return 0;
+    }
+    // Nodes: source, voters, members, an overflow node for the larger shares, and sink.
+    let (source, overflow, sink) = (0, voters + seats + 1, voters + seats + 2);
+    let mut network = FlowNetwork::new(voters + seats + 3);
+    for (v, m) in misrepresentation.iter().enumerate() {
+        network.add_edge(source, 1 + v, 1, 0);
+        for (i, &c) in committee.iter().enumerate() {
+            network.add_edge(1 + v, 1 + voters + i, 1, m[c]);
+        }
+    }
+    for i in 0..seats {
+        network.add_edge(1 + voters + i, sink, voters / seats, 0);
+        network.add_edge(1 + voters + i, overflow, 1, 0);
+    }
+    network.add_edge(overflow, sink, voters % seats, 0);
+    network.min_cost_flow(source, sink)
+}
+
+/// A flow network for computing minimum-cost flows.
+struct FlowNetwork {
+    /// The edges leaving each node, as indices into `edges`.
+    adjacency: Vec<Vec<usize>>,
+    /// Each edge as `(to, capacity, cost)`, stored next to its reverse edge.
+    edges: Vec<(usize, usize, isize)>,
+}
+
+impl FlowNetwork {
+    fn new(nodes: usize) -> Self {
+        Self {
+            adjacency: vec![Vec::new(); nodes],
+            edges: Vec::new(),
+        }
+    }
+    fn add_edge(&mut self, from: usize, to: usize, capacity: usize, cost: usize) {
+        let cost = cost.cast_signed();
+        self.adjacency[from].push(self.edges.len());
+        self.edges.push((to, capacity, cost));
+        self.adjacency[to].push(self.edges.len());
+        self.edges.push((from, 0, -cost));
+    }
+    /// Sends as much flow as possible from `source` to `sink` along successive shortest paths, returning the total cost.
+    fn min_cost_flow(&mut self, source: usize, sink: usize) -> usize {
+        let mut total = 0isize;
+        loop {
+            // Bellman-Ford, since reverse edges have negative costs.
+            let mut distance = vec![isize::MAX; self.adjacency.len()];
+            let mut via = vec![None; self.adjacency.len()];
+            distance[source] = 0;
+            let mut updated = true;
+            while updated {
+                updated = false;
+                for node in 0..self.adjacency.len() {
+                    if distance[node] == isize::MAX {
+                        continue;
+                    }
+                    for &e in &self.adjacency[node] {
+                        let (to, capacity, cost) = self.edges[e];
+                        if capacity > 0 && distance[node] + cost < distance[to] {
+                            distance[to] = distance[node] + cost;
+                            via[to] = Some(e);
+                            updated = true;
+                        }
+                    }
+                }
+            }
+            if distance[sink] == isize::MAX {
+                return total.unsigned_abs();
+            }
+            // Augment along the shortest path.
+            let mut path = Vec::new();
+            let mut node = sink;
+            while let Some(e) = via[node] {
+                path.push(e);
+                node = self.edges[e ^ 1].0;
+            }
+            let flow = path.iter().map(|&e| self.edges[e].1).min().unwrap_or(0);
+            for &e in &path {
+                self.edges[e].1 -= flow;
+                self.edges[e ^ 1].1 += flow;
+            }
+            total += distance[sink] * flow.cast_signed();
+        }
+    }
+}
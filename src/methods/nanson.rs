@@ -0,0 +1,47 @@
+use crate::config::{Component, Describe};
+use crate::core::{Candidate, Id, Method, Ordinal, Profile, SingleWinner, Ties};
+use crate::methods::as_f64;
+use crate::methods::borda::borda_scores;
+use crate::methods::instant_runoff::{Round, eliminate};
+
+/// A single-winner, ranked, Condorcet-consistent voting method. In each round, the Borda scores of the remaining candidates are computed and every candidate scoring below the average is eliminated, until a single candidate remains (or all remaining candidates have the same score, in which case they tie).
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Nanson;
+
+impl Method for Nanson {
+    type Ballot = Ordinal;
+    type Winner = SingleWinner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        self.outcome_with(candidates, profile, &mut Ties::none())
+    }
+    fn outcome_with(
+        &self,
+        candidates: &[Candidate],
+        profile: Profile<Self::Ballot>,
+        ties: &mut Ties,
+    ) -> Self::Winner {
+        eliminate(candidates, &profile, ties, |ballots, remaining, ties| {
+            let scores = borda_scores(candidates, ballots, remaining);
+            ties.record(scores.iter().copied().map(as_f64));
+            let total: usize = remaining.iter().map(|&c| scores[c]).sum();
+            // Compare `score < total / m` without rounding.
+            let losers: Vec<Id> = remaining
+                .iter()
+                .copied()
+                .filter(|&c| scores[c] * remaining.len() < total)
+                .collect();
+            if losers.is_empty() {
+                Round::Elect(remaining.to_vec())
+            } else {
+                Round::Eliminate(losers)
+            }
+        })
+    }
+}
+
+impl Describe for Nanson {
+    fn describe(&self) -> Component {
+        Component::new("nanson")
+    }
+}
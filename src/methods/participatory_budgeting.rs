@@ -0,0 +1,315 @@
+use std::marker::PhantomData;
+
+use serde::{Deserialize, Serialize};
+
+use ordered_float::OrderedFloat;
+
+use crate::config::{Component, Describe};
+use crate::core::{Ballot, Candidate, Cardinal, Id, Method, MultiWinner, Nominal, Profile, Ties};
+use crate::methods::equal_shares::equal_shares;
+use crate::methods::{as_f64, best_of};
+
+/// A participatory budgeting method (greedy approval) for nominal or cardinal ballots.
+///
+/// Candidates are projects, where `costs[c]` is the cost of the project with id `c`, and the outcome is the set of funded projects. Projects are considered in order of their total utility (where an approval is worth one unit of utility, and a score is worth its value), and each is funded if it fits in the remaining budget. Ties are broken by the tie-breaker, and then in favor of the lowest candidate id, and projects without a cost are never funded.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GreedyBudgeting<B: Ballot> {
+    costs: Vec<f64>,
+    budget: f64,
+    #[serde(skip)]
+    ballot: PhantomData<B>,
+}
+
+/// A participatory budgeting method (the Method of Equal Shares) for nominal or cardinal ballots.
+///
+/// Candidates are projects, where `costs[c]` is the cost of the project with id `c`, and the outcome is the set of funded projects. The budget is split equally between the voters, and projects are funded one at a time, each time funding the project that its supporters can afford while paying the lowest price per unit of utility.
+///
+/// By default, any budget left over is spent greedily, in order of total utility (as with [`GreedyBudgeting`]).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EqualSharesBudgeting<B: Ballot> {
+    costs: Vec<f64>,
+    budget: f64,
+    completion: bool,
+    #[serde(skip)]
+    ballot: PhantomData<B>,
+}
+
+/// A participatory budgeting method (Phragmén's sequential method) for nominal ballots.
+///
+/// Candidates are projects, where `costs[c]` is the cost of the project with id `c`, and the outcome is the set of funded projects. Each funded project places a load equal to its cost on the voters that approve of it, which is spread so as to keep the maximum load on any voter as small as possible. Projects are funded one at a time, each time funding the project that fits in the remaining budget and results in the smallest maximum load.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PhragmenBudgeting {
+    costs: Vec<f64>,
+    budget: f64,
+}
+
+impl<B: Ballot> GreedyBudgeting<B> {
+    /// Creates a new instance of greedy budgeting with the specified project costs and budget.
+    #[must_use]
+    pub const fn new(costs: Vec<f64>, budget: f64) -> Self {
+        Self {
+            costs,
+            budget,
+            ballot: PhantomData,
+        }
+    }
+    /// Funds projects in order of total utility.
+    fn funded(
+        &self,
+        candidates: &[Candidate],
+        utilities: &[Vec<(Id, f64)>],
+        ties: &mut Ties,
+    ) -> MultiWinner {
+        let costs = project_costs(&self.costs, candidates);
+        let funded = greedy(&costs, self.budget, utilities, vec![], ties);
+        elected(candidates, &funded)
+    }
+}
+
+impl<B: Ballot> EqualSharesBudgeting<B> {
+    /// Creates a new instance of the Method of Equal Shares with the specified project costs and budget.
+    #[must_use]
+    pub const fn new(costs: Vec<f64>, budget: f64) -> Self {
+        Self {
+            costs,
+            budget,
+            completion: true,
+            ballot: PhantomData,
+        }
+    }
+    /// Leaves any budget left over by the Method of Equal Shares unspent.
+    #[must_use]
+    pub const fn without_completion(mut self) -> Self {
+        self.completion = false;
+        self
+    }
+    /// Funds projects with the Method of Equal Shares, followed by greedy completion.
+    fn funded(
+        &self,
+        candidates: &[Candidate],
+        utilities: &[Vec<(Id, f64)>],
+        ties: &mut Ties,
+    ) -> MultiWinner {
+        let costs = project_costs(&self.costs, candidates);
+        let mut funded = equal_shares(&costs, self.budget, utilities, ties);
+        if self.completion {
+            funded = greedy(&costs, self.budget, utilities, funded, ties);
+        }
+        elected(candidates, &funded)
+    }
+}
+
+impl PhragmenBudgeting {
+    /// Creates a new instance of Phragmén's method with the specified project costs and budget.
+    #[must_use]
+    pub const fn new(costs: Vec<f64>, budget: f64) -> Self {
+        Self { costs, budget }
+    }
+}
+
+impl Method for GreedyBudgeting<Nominal> {
+    type Ballot = Nominal;
+    type Winner = MultiWinner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        self.outcome_with(candidates, profile, &mut Ties::none())
+    }
+    fn outcome_with(
+        &self,
+        candidates: &[Candidate],
+        profile: Profile<Self::Ballot>,
+        ties: &mut Ties,
+    ) -> Self::Winner {
+        self.funded(candidates, &nominal_utilities(&profile), ties)
+    }
+}
+
+impl Method for GreedyBudgeting<Cardinal> {
+    type Ballot = Cardinal;
+    type Winner = MultiWinner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        self.outcome_with(candidates, profile, &mut Ties::none())
+    }
+    fn outcome_with(
+        &self,
+        candidates: &[Candidate],
+        profile: Profile<Self::Ballot>,
+        ties: &mut Ties,
+    ) -> Self::Winner {
+        self.funded(candidates, &cardinal_utilities(&profile), ties)
+    }
+}
+
+impl<B: Ballot> Describe for GreedyBudgeting<B> {
+    fn describe(&self) -> Component {
+        Component::new("greedy_budgeting")
+            .param("costs", self.costs.clone())
+            .param("budget", self.budget)
+    }
+}
+
+impl Method for EqualSharesBudgeting<Nominal> {
+    type Ballot = Nominal;
+    type Winner = MultiWinner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        self.outcome_with(candidates, profile, &mut Ties::none())
+    }
+    fn outcome_with(
+        &self,
+        candidates: &[Candidate],
+        profile: Profile<Self::Ballot>,
+        ties: &mut Ties,
+    ) -> Self::Winner {
+        self.funded(candidates, &nominal_utilities(&profile), ties)
+    }
+}
+
+impl Method for EqualSharesBudgeting<Cardinal> {
+    type Ballot = Cardinal;
+    type Winner = MultiWinner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        self.outcome_with(candidates, profile, &mut Ties::none())
+    }
+    fn outcome_with(
+        &self,
+        candidates: &[Candidate],
+        profile: Profile<Self::Ballot>,
+        ties: &mut Ties,
+    ) -> Self::Winner {
+        self.funded(candidates, &cardinal_utilities(&profile), ties)
+    }
+}
+
+impl<B: Ballot> Describe for EqualSharesBudgeting<B> {
+    fn describe(&self) -> Component {
+        Component::new("equal_shares_budgeting")
+            .param("costs", self.costs.clone())
+            .param("budget", self.budget)
+            .param("completion", self.completion)
+    }
+}
+
+impl Method for PhragmenBudgeting {
+    type Ballot = Nominal;
+    type Winner = MultiWinner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        self.outcome_with(candidates, profile, &mut Ties::none())
+    }
+    fn outcome_with(
+        &self,
+        candidates: &[Candidate],
+        profile: Profile<Self::Ballot>,
+        ties: &mut Ties,
+    ) -> Self::Winner {
+        let costs = project_costs(&self.costs, candidates);
+        let mut funded: Vec<Id> = Vec::new();
+        let mut spent = 0.0;
+        let mut loads = vec![0.0f64; profile.len()];
+        loop {
+            // The maximum load on the supporters of each project, if it were funded.
+            let (supporters, supporter_loads) = profile.iter().zip(&loads).fold(
+                (
+                    vec![0usize; candidates.len()],
+                    vec![0.0f64; candidates.len()],
+                ),
+                |(mut supporters, mut supporter_loads), (b, load)| {
+                    for c in b.iter() {
+                        supporters[*c] += 1;
+                        supporter_loads[*c] += load;
+                    }
+                    (supporters, supporter_loads)
+                },
+            );
+            let new_load = |c: Id| (costs[c] + supporter_loads[c]) / as_f64(supporters[c]);
+            let contenders = (0..candidates.len())
+                .filter(|c| !funded.contains(c) && supporters[*c] > 0)
+                .filter(|&c| spent + costs[c] <= self.budget + EPSILON);
+            let next = best_of(contenders, |c| -new_load(c), ties);
+            let Some(next) = next else {
+                break;
+            };
+            let load = new_load(next);
+            for (b, l) in profile.iter().zip(loads.iter_mut()) {
+                if b.contains(&next) {
+                    *l = load;
+                }
+            }
+            spent += costs[next];
+            funded.push(next);
+        }
+        elected(candidates, &funded)
+    }
+}
+
+impl Describe for PhragmenBudgeting {
+    fn describe(&self) -> Component {
+        Component::new("phragmen_budgeting")
+            .param("costs", self.costs.clone())
+            .param("budget", self.budget)
+    }
+}
+
+/// The tolerance used when checking whether projects fit in the budget.
+const EPSILON: f64 = 1e-9;
+
+/// The cost of each project, where projects without a cost are unaffordable.
+fn project_costs(costs: &[f64], candidates: &[Candidate]) -> Vec<f64> {
+    (0..candidates.len())
+        .map(|c| costs.get(c).copied().unwrap_or(f64::INFINITY))
+        .collect()
+}
+
+/// Funds projects greedily in order of total utility, starting from the already `funded` projects.
+fn greedy(
+    costs: &[f64],
+    budget: f64,
+    utilities: &[Vec<(Id, f64)>],
+    mut funded: Vec<Id>,
+    ties: &mut Ties,
+) -> Vec<Id> {
+    let mut totals = vec![0.0f64; costs.len()];
+    for &(c, utility) in utilities.iter().flatten() {
+        totals[c] += utility;
+    }
+    ties.record(totals.iter().copied());
+    let contenders: Vec<Id> = (0..costs.len()).filter(|&c| totals[c] > 0.0).collect();
+    let order = ties.rank(&contenders, |c| OrderedFloat(totals[c])).concat();
+    let mut spent: f64 = funded.iter().map(|&c| costs[c]).sum();
+    for c in order {
+        if !funded.contains(&c) && spent + costs[c] <= budget + EPSILON {
+            spent += costs[c];
+            funded.push(c);
+        }
+    }
+    funded
+}
+
+fn nominal_utilities(profile: &Profile<Nominal>) -> Vec<Vec<(Id, f64)>> {
+    profile
+        .iter()
+        .map(|b| b.iter().map(|&c| (c, 1.0)).collect())
+        .collect()
+}
+
+fn cardinal_utilities(profile: &Profile<Cardinal>) -> Vec<Vec<(Id, f64)>> {
+    profile
+        .iter()
+        .map(|b| {
+            b.iter()
+                .filter(|(_, score)| **score > 0)
+                .map(|(&c, &score)| (c, as_f64(score)))
+                .collect()
+        })
+        .collect()
+}
+
+fn elected(candidates: &[Candidate], funded: &[Id]) -> MultiWinner {
+    if funded.is_empty() {
+        return MultiWinner::none();
+    }
+    MultiWinner::seats(candidates, funded)
+}
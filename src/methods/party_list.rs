@@ -0,0 +1,248 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::{Component, Describe};
+use crate::core::{Candidate, Id, Method, MultiWinner, Ordinal, Profile, Ties};
+use crate::methods::{as_f64, best_of};
+
+/// A multi-winner, party-list proportional representation method.
+///
+/// Each ballot's first preference counts as a vote for that candidate's party, and seats are apportioned between the parties in proportion to their votes. Each party's seats go to its candidates in list order (the order in which they appear among the candidates).
+///
+/// Candidates without a party run as independents, on a list of their own. A party is never apportioned more seats than it has candidates.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartyList {
+    seats: usize,
+    apportionment: Apportionment,
+}
+
+/// The rule used to apportion seats between parties.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Apportionment {
+    /// Highest averages with divisors `1, 2, 3, ...`, which slightly favors larger parties.
+    DHondt,
+    /// Highest averages with divisors `1, 3, 5, ...`.
+    SainteLague,
+    /// Highest averages with divisors `1.4, 3, 5, ...`, which makes the first seat harder for small parties to win.
+    ModifiedSainteLague,
+    /// Largest remainder (Hamilton): each party receives a seat for every full quota of votes, and remaining seats go to the parties with the largest remainders.
+    LargestRemainder(Quota),
+}
+
+/// The number of votes a candidate or party needs to be guaranteed a seat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Quota {
+    /// `votes / seats`
+    Hare,
+    /// `floor(votes / (seats + 1)) + 1`
+    Droop,
+}
+
+impl Quota {
+    /// The name of the quota in election configs.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Hare => "hare",
+            Self::Droop => "droop",
+        }
+    }
+    /// Computes the quota for the specified number of votes and seats.
+    #[must_use]
+    pub fn threshold(&self, votes: f64, seats: usize) -> f64 {
+        let seats = as_f64(seats);
+        match self {
+            Self::Hare => votes / seats,
+            Self::Droop => (votes / (seats + 1.0)).floor() + 1.0,
+        }
+    }
+    /// Computes the quota for fractional votes, as in Meek's method, where the Droop quota is `votes / (seats + 1)` plus a small epsilon, so that no more than `seats` candidates can reach it.
+    #[must_use]
+    pub fn fractional_threshold(&self, votes: f64, seats: usize) -> f64 {
+        /// Well above the tolerance of a count, and well below a single vote.
+        const EPSILON: f64 = 1e-6;
+        match self {
+            Self::Hare => self.threshold(votes, seats),
+            Self::Droop => votes / (as_f64(seats) + 1.0) + EPSILON,
+        }
+    }
+}
+
+impl PartyList {
+    /// Creates a new party-list method with the specified number of seats and apportionment rule.
+    #[must_use]
+    pub const fn new(seats: usize, apportionment: Apportionment) -> Self {
+        Self {
+            seats,
+            apportionment,
+        }
+    }
+    /// Creates a new party-list method using the D'Hondt apportionment rule.
+    #[must_use]
+    pub const fn dhondt(seats: usize) -> Self {
+        Self::new(seats, Apportionment::DHondt)
+    }
+    /// Creates a new party-list method using the Sainte-Laguë apportionment rule.
+    #[must_use]
+    pub const fn sainte_lague(seats: usize) -> Self {
+        Self::new(seats, Apportionment::SainteLague)
+    }
+    /// Creates a new party-list method using the Hamilton (largest remainder) apportionment rule.
+    #[must_use]
+    pub const fn hamilton(seats: usize, quota: Quota) -> Self {
+        Self::new(seats, Apportionment::LargestRemainder(quota))
+    }
+    /// Apportions seats between parties with the given votes and lists.
+    fn apportion(&self, votes: &[f64], lists: &[Vec<Id>], ties: &mut Ties) -> Vec<usize> {
+        let lengths: Vec<usize> = lists.iter().map(Vec::len).collect();
+        let mut seats = vec![0usize; votes.len()];
+        let available = self.seats.min(lengths.iter().sum());
+        match self.apportionment {
+            Apportionment::LargestRemainder(quota) => {
+                let quota = quota.threshold(votes.iter().sum(), self.seats);
+                let mut remainders = vec![0.0; votes.len()];
+                for (p, &v) in votes.iter().enumerate() {
+                    let full_quotas = if quota > 0.0 {
+                        (v / quota).floor()
+                    } else {
+                        0.0
+                    };
+                    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                    let full_quota_seats = full_quotas as usize;
+                    seats[p] = lengths[p].min(full_quota_seats);
+                    remainders[p] = quota.mul_add(-full_quotas, v);
+                }
+                // Cap at the number of seats (the quota may be too small).
+                while seats.iter().sum::<usize>() > available {
+                    let p = (0..votes.len()).rfind(|&p| seats[p] > 0).unwrap_or(0);
+                    seats[p] -= 1;
+                }
+                while seats.iter().sum::<usize>() < available {
+                    let Some(p) = largest(&remainders, lists, |p| seats[p] < lengths[p], ties)
+                        .filter(|&p| remainders[p].is_finite())
+                    else {
+                        break;
+                    };
+                    seats[p] += 1;
+                    remainders[p] = f64::NEG_INFINITY;
+                }
+                // Any seats still open go to the parties with the most votes per seat.
+                while seats.iter().sum::<usize>() < available {
+                    let averages: Vec<f64> = (0..votes.len())
+                        .map(|p| votes[p] / (as_f64(seats[p]) + 1.0))
+                        .collect();
+                    let Some(p) = largest(&averages, lists, |p| seats[p] < lengths[p], ties) else {
+                        break;
+                    };
+                    seats[p] += 1;
+                }
+            }
+            divisors => {
+                let divisor = |won: usize| -> f64 {
+                    match divisors {
+                        Apportionment::ModifiedSainteLague if won == 0 => 1.4,
+                        Apportionment::SainteLague | Apportionment::ModifiedSainteLague => {
+                            2.0f64.mul_add(as_f64(won), 1.0)
+                        }
+                        _ => as_f64(won) + 1.0,
+                    }
+                };
+                for _ in 0..available {
+                    let averages: Vec<f64> = (0..votes.len())
+                        .map(|p| votes[p] / divisor(seats[p]))
+                        .collect();
+                    let Some(p) = largest(&averages, lists, |p| seats[p] < lengths[p], ties) else {
+                        break;
+                    };
+                    seats[p] += 1;
+                }
+            }
+        }
+        seats
+    }
+}
+
+impl Method for PartyList {
+    type Ballot = Ordinal;
+    type Winner = MultiWinner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        self.outcome_with(candidates, profile, &mut Ties::none())
+    }
+    fn outcome_with(
+        &self,
+        candidates: &[Candidate],
+        profile: Profile<Self::Ballot>,
+        ties: &mut Ties,
+    ) -> Self::Winner {
+        // Group the candidates into lists, in order of appearance.
+        let mut lists: Vec<(Option<&str>, Vec<Id>)> = Vec::new();
+        let mut list_of = vec![0usize; candidates.len()];
+        for candidate in candidates {
+            let party = candidate.party();
+            let position = party
+                .and_then(|party| lists.iter().position(|(p, _)| *p == Some(party)))
+                .unwrap_or_else(|| {
+                    lists.push((party, Vec::new()));
+                    lists.len() - 1
+                });
+            lists[position].1.push(candidate.id());
+            list_of[candidate.id()] = position;
+        }
+
+        let votes = profile
+            .iter()
+            .fold(vec![0.0f64; lists.len()], |mut votes, b| {
+                if let Some(first_place_candidate) = b.first() {
+                    votes[list_of[*first_place_candidate]] += 1.0;
+                }
+                votes
+            });
+        let lists: Vec<Vec<Id>> = lists.into_iter().map(|(_, list)| list).collect();
+        let seats = self.apportion(&votes, &lists, ties);
+
+        let elected: Vec<Id> = lists
+            .iter()
+            .zip(seats)
+            .flat_map(|(list, seats)| list.iter().copied().take(seats))
+            .collect();
+        if elected.is_empty() {
+            return MultiWinner::none();
+        }
+        MultiWinner::seats(candidates, &elected)
+    }
+}
+
+impl Describe for PartyList {
+    fn describe(&self) -> Component {
+        let component = Component::new("party_list").param("seats", self.seats);
+        match self.apportionment {
+            Apportionment::DHondt => component.param("apportionment", "dhondt"),
+            Apportionment::SainteLague => component.param("apportionment", "sainte_lague"),
+            Apportionment::ModifiedSainteLague => {
+                component.param("apportionment", "modified_sainte_lague")
+            }
+            Apportionment::LargestRemainder(quota) => component
+                .param("apportionment", "largest_remainder")
+                .param("quota", quota.name()),
+        }
+    }
+}
+
+/// Finds the eligible party with the largest value. Ties are broken by the tie-breaker between the parties' first candidates, and then in favor of earlier parties.
+fn largest(
+    values: &[f64],
+    lists: &[Vec<Id>],
+    eligible: impl Fn(usize) -> bool,
+    ties: &mut Ties,
+) -> Option<usize> {
+    let party = |c: Id| lists.iter().position(|list| list.first() == Some(&c));
+    let heads = (0..values.len())
+        .filter(|&p| eligible(p))
+        .filter_map(|p| lists[p].first().copied());
+    best_of(
+        heads,
+        |c| party(c).map_or(f64::NEG_INFINITY, |p| values[p]),
+        ties,
+    )
+    .and_then(party)
+}
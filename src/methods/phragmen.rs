@@ -0,0 +1,106 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::{Component, Describe};
+use crate::core::{Candidate, Id, Method, MultiWinner, Nominal, Profile, Ties};
+use crate::methods::{as_f64, best_of};
+
+/// A multi-winner, nominal voting method (Phragmén's sequential method).
+///
+/// Each elected candidate places a load of one unit on the voters that approve of them, which is spread so as to keep the maximum load on any voter as small as possible. Candidates are elected one at a time, each time electing the candidate that results in the smallest maximum load (with ties broken by the tie-breaker, and then in favor of the lowest candidate id).
+///
+/// Once no remaining candidate is approved by any voter, the remaining seats are filled in order of candidate id.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Phragmen {
+    seats: usize,
+}
+
+impl Phragmen {
+    /// Creates a new instance of Phragmén's method with the specified number of seats.
+    #[must_use]
+    pub const fn new(seats: usize) -> Self {
+        Self { seats }
+    }
+}
+
+impl Method for Phragmen {
+    type Ballot = Nominal;
+    type Winner = MultiWinner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        self.outcome_with(candidates, profile, &mut Ties::none())
+    }
+    fn outcome_with(
+        &self,
+        candidates: &[Candidate],
+        profile: Profile<Self::Ballot>,
+        ties: &mut Ties,
+    ) -> Self::Winner {
+        let committee = sequential_phragmen(
+            candidates,
+            &profile,
+            self.seats,
+            vec![],
+            vec![0.0; profile.len()],
+            ties,
+        );
+        if committee.is_empty() {
+            return MultiWinner::none();
+        }
+        MultiWinner::seats(candidates, &committee)
+    }
+}
+
+impl Describe for Phragmen {
+    fn describe(&self) -> Component {
+        Component::new("phragmen").param("seats", self.seats)
+    }
+}
+
+/// Elects candidates with Phragmén's sequential method until `seats` candidates are elected, starting from an existing `committee` and the load it already places on each voter.
+pub fn sequential_phragmen(
+    candidates: &[Candidate],
+    profile: &Profile<Nominal>,
+    seats: usize,
+    mut committee: Vec<Id>,
+    mut loads: Vec<f64>,
+    ties: &mut Ties,
+) -> Vec<Id> {
+    let seats = seats.min(candidates.len());
+    while committee.len() < seats {
+        // The maximum load on the supporters of each candidate, if they were elected.
+        let (supporters, supporter_loads) = profile.iter().zip(&loads).fold(
+            (
+                vec![0usize; candidates.len()],
+                vec![0.0f64; candidates.len()],
+            ),
+            |(mut supporters, mut supporter_loads), (b, load)| {
+                for c in b.iter() {
+                    supporters[*c] += 1;
+                    supporter_loads[*c] += load;
+                }
+                (supporters, supporter_loads)
+            },
+        );
+        let new_load = |c: Id| (1.0 + supporter_loads[c]) / as_f64(supporters[c]);
+        let contenders =
+            (0..candidates.len()).filter(|c| !committee.contains(c) && supporters[*c] > 0);
+        let elected = best_of(contenders, |c| -new_load(c), ties);
+        let Some(elected) = elected else {
+            // Nobody approves of the remaining candidates.
+            for c in 0..candidates.len() {
+                if committee.len() < seats && !committee.contains(&c) {
+                    committee.push(c);
+                }
+            }
+            break;
+        };
+        let load = new_load(elected);
+        for (b, l) in profile.iter().zip(loads.iter_mut()) {
+            if b.contains(&elected) {
+                *l = load;
+            }
+        }
+        committee.push(elected);
+    }
+    committee
+}
@@ -1,8 +1,9 @@
+use crate::config::{Component, Describe};
 use crate::core::{Candidate, Method, Ordinal, Profile, SingleWinner};
 use crate::methods::find_candidates_with_value;
 
 /// A single-winner, ranked voting method. The candidate with the most votes (a plurality) wins.
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Plurality;
 
 impl Method for Plurality {
@@ -28,3 +29,9 @@ impl Method for Plurality {
         }
     }
 }
+
+impl Describe for Plurality {
+    fn describe(&self) -> Component {
+        Component::new("plurality")
+    }
+}
@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::{Component, Describe};
+use crate::core::{Candidate, Id, Method, Ordinal, Profile, SingleWinner};
+use crate::methods::{as_f64, find_candidates_with_value};
+
+/// A single-winner, ranked voting method parameterized by a scoring vector. Each ballot gives `scores[i]` points to the candidate it ranks in position `i`, and the candidate with the most points wins.
+///
+/// Positions past the end of the scoring vector, and candidates left off a ballot, receive no points.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Positional {
+    scores: Vec<f64>,
+}
+
+impl Positional {
+    /// Creates a new positional method with the specified scoring vector.
+    #[must_use]
+    pub const fn new(scores: Vec<f64>) -> Self {
+        Self { scores }
+    }
+    /// The Borda count for `n` candidates: `(n - 1, n - 2, ..., 1, 0)`.
+    #[must_use]
+    pub fn borda(n: usize) -> Self {
+        Self::new((0..n).rev().map(as_f64).collect())
+    }
+    /// The Dowdall (Nauru) system for `n` candidates: `(1, 1/2, 1/3, ..., 1/n)`.
+    #[must_use]
+    pub fn dowdall(n: usize) -> Self {
+        Self::new((1..=n).map(|i| 1.0 / as_f64(i)).collect())
+    }
+    /// Anti-plurality (veto) for `n` candidates: `(1, 1, ..., 1, 0)`. Every candidate but the last ranked receives a point.
+    #[must_use]
+    pub fn anti_plurality(n: usize) -> Self {
+        let mut scores = vec![1.0; n];
+        if let Some(last) = scores.last_mut() {
+            *last = 0.0;
+        }
+        Self::new(scores)
+    }
+    /// k-approval: `(1, ..., 1, 0, ..., 0)`. Each of the top `k` candidates receives a point.
+    #[must_use]
+    pub fn k_approval(k: usize) -> Self {
+        Self::new(vec![1.0; k])
+    }
+    /// Plurality: `(1, 0, ..., 0)`. Only the first-ranked candidate receives a point.
+    #[must_use]
+    pub fn plurality() -> Self {
+        Self::k_approval(1)
+    }
+    /// The scoring vector
+    #[must_use]
+    pub fn scores(&self) -> &[f64] {
+        &self.scores
+    }
+}
+
+impl Method for Positional {
+    type Ballot = Ordinal;
+    type Winner = SingleWinner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        // Count placements per position first, so that candidates with the same placements receive exactly the same score.
+        let placements = profile.iter().fold(
+            vec![vec![0.0f64; self.scores.len()]; candidates.len()],
+            |mut placements, b| {
+                for (i, candidate) in b.iter().take(self.scores.len()).enumerate() {
+                    placements[*candidate][i] += 1.0;
+                }
+                placements
+            },
+        );
+        let totals: Vec<f64> = placements
+            .iter()
+            .map(|counts| counts.iter().zip(&self.scores).map(|(n, s)| n * s).sum())
+            .collect();
+        let max_total = totals.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let winners: Vec<Id> = find_candidates_with_value(&totals, &max_total);
+        match winners.len() {
+            0 => SingleWinner::none(),
+            1 => SingleWinner::win(candidates, winners[0]),
+            _ => SingleWinner::tie(candidates, &winners),
+        }
+    }
+}
+
+impl Describe for Positional {
+    fn describe(&self) -> Component {
+        Component::new("positional").param("scores", self.scores.clone())
+    }
+}
@@ -0,0 +1,267 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::{Component, Describe};
+use crate::core::{Candidate, Id, Method, MultiWinner, Nominal, Profile, Ties};
+use crate::methods::{MethodError, SEARCH_LIMIT, as_f64, best_of, choose_committee};
+
+/// A multi-winner, nominal voting method (Proportional Approval Voting).
+///
+/// The elected committee maximizes the sum over voters of the harmonic number `1 + 1/2 + ... + 1/k`, where `k` is the number of committee members the voter approves of.
+///
+/// The exact solver searches every committee, which is only feasible for small elections: when there are more than a million committees, it falls back to the approximate solver. The approximate solver elects a committee with [`SequentialPAV`] and then improves it by swapping members for non-members until no swap increases the score. Ties between committees with the same score are broken by the tie-breaker, and then in favor of the lexicographically smallest committee (by candidate id).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PAV {
+    seats: usize,
+    solver: Solver,
+}
+
+/// A multi-winner, nominal voting method (Sequential Proportional Approval Voting).
+///
+/// Candidates are elected one at a time, each time electing the candidate that increases the PAV score the most. In other words, each voter's approval is worth `1 / (k + 1)`, where `k` is the number of already elected candidates the voter approves of. Ties are broken by the tie-breaker, and then in favor of the lowest candidate id.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SequentialPAV {
+    seats: usize,
+}
+
+/// The kind of solver used by methods whose optimal outcome is expensive to compute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Solver {
+    /// Find the optimal outcome by exhaustive search.
+    Exact,
+    /// Find a good (but not necessarily optimal) outcome in polynomial time.
+    Approximate,
+}
+
+impl Solver {
+    /// The name of the solver in election configs.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Exact => "exact",
+            Self::Approximate => "approximate",
+        }
+    }
+}
+
+impl PAV {
+    /// Creates a new instance of PAV with the specified number of seats and solver.
+    #[must_use]
+    pub const fn new(seats: usize, solver: Solver) -> Self {
+        Self { seats, solver }
+    }
+    /// Creates a new instance of PAV with the specified number of seats, solved exactly.
+    #[must_use]
+    pub const fn exact(seats: usize) -> Self {
+        Self::new(seats, Solver::Exact)
+    }
+    /// Creates a new instance of PAV with the specified number of seats, solved by local search.
+    #[must_use]
+    pub const fn local_search(seats: usize) -> Self {
+        Self::new(seats, Solver::Approximate)
+    }
+}
+
+impl SequentialPAV {
+    /// Creates a new instance of Sequential PAV with the specified number of seats.
+    #[must_use]
+    pub const fn new(seats: usize) -> Self {
+        Self { seats }
+    }
+}
+
+impl Method for PAV {
+    type Ballot = Nominal;
+    type Winner = MultiWinner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        self.outcome_with(candidates, profile, &mut Ties::none())
+    }
+    fn outcome_with(
+        &self,
+        candidates: &[Candidate],
+        profile: Profile<Self::Ballot>,
+        ties: &mut Ties,
+    ) -> Self::Winner {
+        let seats = self.seats.min(candidates.len());
+        let committee = match self.solver {
+            Solver::Exact => exact_pav(candidates, &profile, seats, ties)
+                .unwrap_or_else(|_| local_search_pav(candidates, &profile, seats, ties)),
+            Solver::Approximate => local_search_pav(candidates, &profile, seats, ties),
+        };
+        if committee.is_empty() {
+            return MultiWinner::none();
+        }
+        MultiWinner::seats(candidates, &committee)
+    }
+}
+
+impl Describe for PAV {
+    fn describe(&self) -> Component {
+        Component::new("pav")
+            .param("seats", self.seats)
+            .param("solver", self.solver.name())
+    }
+}
+
+impl Method for SequentialPAV {
+    type Ballot = Nominal;
+    type Winner = MultiWinner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        self.outcome_with(candidates, profile, &mut Ties::none())
+    }
+    fn outcome_with(
+        &self,
+        candidates: &[Candidate],
+        profile: Profile<Self::Ballot>,
+        ties: &mut Ties,
+    ) -> Self::Winner {
+        let committee = sequential_pav(candidates, &profile, self.seats, ties);
+        if committee.is_empty() {
+            return MultiWinner::none();
+        }
+        MultiWinner::seats(candidates, &committee)
+    }
+}
+
+impl Describe for SequentialPAV {
+    fn describe(&self) -> Component {
+        Component::new("sequential_pav").param("seats", self.seats)
+    }
+}
+
+/// The tolerance used when comparing scores, so that rounding errors do not break ties.
+const EPSILON: f64 = 1e-9;
+
+/// Computes the PAV score of a committee.
+fn pav_score(profile: &Profile<Nominal>, committee: &[Id]) -> f64 {
+    profile
+        .iter()
+        .map(|b| {
+            let approved = committee.iter().filter(|c| b.contains(c)).count();
+            (1..=approved).map(|k| 1.0 / as_f64(k)).sum::<f64>()
+        })
+        .sum()
+}
+
+/// Finds the committees with the highest PAV score by searching every committee.
+fn exact_pav(
+    candidates: &[Candidate],
+    profile: &Profile<Nominal>,
+    seats: usize,
+    ties: &mut Ties,
+) -> Result<Vec<Id>, MethodError> {
+    check_committees(candidates.len(), seats)?;
+    let mut best: Option<(f64, Vec<Vec<Id>>)> = None;
+    for_each_committee(candidates.len(), seats, |committee| {
+        let score = pav_score(profile, committee);
+        match &mut best {
+            Some((best, optimal)) if score <= *best + EPSILON => {
+                if score >= *best - EPSILON {
+                    optimal.push(committee.to_vec());
+                }
+            }
+            _ => best = Some((score, vec![committee.to_vec()])),
+        }
+    });
+    Ok(best
+        .map(|(_, optimal)| choose_committee(optimal, ties))
+        .unwrap_or_default())
+}
+
+/// Elects a committee with Sequential PAV, then swaps members for non-members while that increases the PAV score.
+fn local_search_pav(
+    candidates: &[Candidate],
+    profile: &Profile<Nominal>,
+    seats: usize,
+    ties: &mut Ties,
+) -> Vec<Id> {
+    let mut committee = sequential_pav(candidates, profile, seats, ties);
+    let mut score = pav_score(profile, &committee);
+    'search: loop {
+        for i in 0..committee.len() {
+            for c in (0..candidates.len()).filter(|c| !committee.contains(c)) {
+                let mut swapped = committee.clone();
+                swapped[i] = c;
+                let swapped_score = pav_score(profile, &swapped);
+                if swapped_score > score + EPSILON {
+                    (committee, score) = (swapped, swapped_score);
+                    continue 'search;
+                }
+            }
+        }
+        break;
+    }
+    committee
+}
+
+/// Elects candidates one at a time by their marginal contribution to the PAV score.
+fn sequential_pav(
+    candidates: &[Candidate],
+    profile: &Profile<Nominal>,
+    seats: usize,
+    ties: &mut Ties,
+) -> Vec<Id> {
+    let mut committee: Vec<Id> = Vec::with_capacity(seats);
+    let mut satisfaction = vec![0usize; profile.len()];
+    while committee.len() < seats.min(candidates.len()) {
+        let gains = profile.iter().zip(&satisfaction).fold(
+            vec![0.0; candidates.len()],
+            |mut gains, (b, &k)| {
+                for c in b.iter() {
+                    gains[*c] += 1.0 / as_f64(k + 1);
+                }
+                gains
+            },
+        );
+        let contenders = (0..candidates.len()).filter(|c| !committee.contains(c));
+        let Some(elected) = best_of(contenders, |c| gains[c], ties) else {
+            break;
+        };
+        for (b, k) in profile.iter().zip(satisfaction.iter_mut()) {
+            if b.contains(&elected) {
+                *k += 1;
+            }
+        }
+        committee.push(elected);
+    }
+    committee
+}
+
+/// Checks that there are few enough committees of `seats` candidates to search them all.
+///
+/// # Errors
+///
+/// Returns an error if there are more committees than [`SEARCH_LIMIT`].
+pub fn check_committees(candidates: usize, seats: usize) -> Result<(), MethodError> {
+    // C(candidates, seats), computed one factor at a time so that it stops once it passes the limit.
+    let mut committees: usize = 1;
+    for i in 0..seats.min(candidates.saturating_sub(seats)) {
+        committees = committees * (candidates - i) / (i + 1);
+        if committees > SEARCH_LIMIT {
+            return Err(MethodError::TooManyStates {
+                limit: SEARCH_LIMIT,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Calls `f` on every committee of `seats` candidates, in lexicographic order.
+pub fn for_each_committee(candidates: usize, seats: usize, mut f: impl FnMut(&[Id])) {
+    if seats > candidates {
+        return;
+    }
+    let mut committee: Vec<Id> = (0..seats).collect();
+    loop {
+        f(&committee);
+        // Advance to the next combination.
+        let Some(i) = (0..seats).rfind(|&i| committee[i] < candidates - seats + i) else {
+            return;
+        };
+        committee[i] += 1;
+        for j in i + 1..seats {
+            committee[j] = committee[j - 1] + 1;
+        }
+    }
+}
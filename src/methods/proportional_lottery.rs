@@ -0,0 +1,36 @@
+use crate::config::{Component, Describe};
+use crate::core::{Candidate, Lottery, Method, Nominal, Profile};
+use crate::methods::as_f64;
+
+/// A single-winner, nominal, probabilistic voting method. Each candidate wins with probability proportional to their number of approvals.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ProportionalLottery;
+
+impl Method for ProportionalLottery {
+    type Ballot = Nominal;
+    type Winner = Lottery;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        let mut approvals = vec![0usize; candidates.len()];
+        for b in profile.iter() {
+            for &c in b.iter() {
+                approvals[c] += 1;
+            }
+        }
+        let total: usize = approvals.iter().sum();
+        if total == 0 {
+            return Lottery::none();
+        }
+        let probabilities: Vec<f64> = approvals
+            .iter()
+            .map(|&a| as_f64(a) / as_f64(total))
+            .collect();
+        Lottery::distribution(candidates, &probabilities)
+    }
+}
+
+impl Describe for ProportionalLottery {
+    fn describe(&self) -> Component {
+        Component::new("proportional_lottery")
+    }
+}
@@ -1,18 +1,33 @@
-use crate::core::{Candidate, Method, Ordinal, Profile, SingleWinner};
+use crate::config::{Component, Describe};
+use crate::core::{Candidate, Lottery, Method, Ordinal, Profile};
+use crate::methods::as_f64;
+use crate::methods::instant_runoff::first_place_counts;
 
-/// A single-winner ranked voting method. The winner is determined by selecting a random ballot and returning the winner(s) of that ballot. That ballot is the only ballot that matters, hence the title "random dictator".
-#[derive(Debug, Clone, serde::Serialize)]
+/// A single-winner ranked voting method.
+///
+/// The winner is determined by selecting a random ballot and returning the winner(s) of that ballot. That ballot is the only ballot that matters, hence the title "random dictator".
+///
+/// The outcome is the lottery that this produces: each candidate wins with probability proportional to the number of ballots ranking them first. Empty ballots are never selected.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct RandomDictator;
 
 impl Method for RandomDictator {
     type Ballot = Ordinal;
-    type Winner = SingleWinner;
+    type Winner = Lottery;
     #[inline]
     fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
-        if profile.is_empty() {
-            return SingleWinner::None;
+        let counts = first_place_counts(candidates, &profile);
+        let total: usize = counts.iter().sum();
+        if total == 0 {
+            return Lottery::none();
         }
-        let winner = profile[0][0];
-        SingleWinner::win(candidates, winner)
+        let probabilities: Vec<f64> = counts.iter().map(|&c| as_f64(c) / as_f64(total)).collect();
+        Lottery::distribution(candidates, &probabilities)
+    }
+}
+
+impl Describe for RandomDictator {
+    fn describe(&self) -> Component {
+        Component::new("random_dictator")
     }
 }
@@ -0,0 +1,122 @@
+use std::cmp::Reverse;
+
+use crate::config::{Component, Describe};
+use crate::core::{Candidate, Id, Method, Ordinal, Profile, SingleWinner, Ties};
+use crate::methods::pairwise_preferences;
+
+/// A single-winner, ranked, Condorcet-consistent voting method (also known as Tideman's method).
+///
+/// Pairwise victories are sorted from strongest to weakest and locked in one at a time, skipping any victory that would create a cycle with those already locked. The winner is the source of the locked graph, i.e. the candidate that no locked victory points to.
+///
+/// Pairs of equal strength are ordered by a ranking of the candidates drawn from the tie-breaker (see [`Method::break_ties`]), falling back to candidate id for candidates it cannot tell apart: the pair whose winner is ranked highest is locked first, and for pairs with the same winner, the one whose loser is ranked lowest is locked first.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RankedPairs {
+    strength: Strength,
+}
+
+/// The measure used to compare the strength of pairwise victories.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum Strength {
+    /// The difference between the number of voters that prefer the winner and the number that prefer the loser.
+    Margins,
+    /// The number of voters that prefer the winner, with fewer opposing votes breaking ties.
+    WinningVotes,
+}
+
+impl RankedPairs {
+    /// Creates a new instance of Ranked Pairs with the specified strength measure.
+    #[must_use]
+    pub const fn new(strength: Strength) -> Self {
+        Self { strength }
+    }
+}
+
+impl Default for RankedPairs {
+    fn default() -> Self {
+        Self::new(Strength::Margins)
+    }
+}
+
+impl Method for RankedPairs {
+    type Ballot = Ordinal;
+    type Winner = SingleWinner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        self.outcome_with(candidates, profile, &mut Ties::none())
+    }
+    fn outcome_with(
+        &self,
+        candidates: &[Candidate],
+        profile: Profile<Self::Ballot>,
+        ties: &mut Ties,
+    ) -> Self::Winner {
+        let n = candidates.len();
+        let d = pairwise_preferences(candidates, &profile);
+
+        // The position of each candidate in the tie-breaking ranking.
+        let ids: Vec<Id> = (0..n).collect();
+        let mut precedence = vec![0; n];
+        for (position, c) in ties.rank(&ids, |_| ()).into_iter().flatten().enumerate() {
+            precedence[c] = position;
+        }
+
+        // Sort the pairwise victories from strongest to weakest.
+        let mut victories: Vec<(Id, Id)> = (0..n)
+            .flat_map(|i| (0..n).map(move |j| (i, j)))
+            .filter(|&(i, j)| d[i][j] > d[j][i])
+            .collect();
+        victories.sort_by_key(|&(winner, loser)| {
+            let strength = match self.strength {
+                Strength::Margins => (d[winner][loser] - d[loser][winner], 0),
+                Strength::WinningVotes => (d[winner][loser], usize::MAX - d[loser][winner]),
+            };
+            (
+                Reverse(strength),
+                precedence[winner],
+                Reverse(precedence[loser]),
+            )
+        });
+
+        // Lock in victories that do not create a cycle.
+        let mut locked = vec![vec![false; n]; n];
+        for (winner, loser) in victories {
+            if !reaches(&locked, loser, winner) {
+                locked[winner][loser] = true;
+            }
+        }
+
+        let winners: Vec<Id> = (0..n)
+            .filter(|&c| (0..n).all(|other| !locked[other][c]))
+            .collect();
+        match winners.len() {
+            0 => SingleWinner::none(),
+            1 => SingleWinner::win(candidates, winners[0]),
+            _ => SingleWinner::tie(candidates, &winners),
+        }
+    }
+}
+
+impl Describe for RankedPairs {
+    fn describe(&self) -> Component {
+        let strength = match self.strength {
+            Strength::Margins => "margins",
+            Strength::WinningVotes => "winning_votes",
+        };
+        Component::new("ranked_pairs").param("strength", strength)
+    }
+}
+
+/// Whether there is a path from `from` to `to` in the locked graph.
+fn reaches(locked: &[Vec<bool>], from: Id, to: Id) -> bool {
+    let mut visited = vec![false; locked.len()];
+    let mut stack = vec![from];
+    while let Some(c) = stack.pop() {
+        if c == to {
+            return true;
+        }
+        if !std::mem::replace(&mut visited[c], true) {
+            stack.extend((0..locked.len()).filter(|&next| locked[c][next] && !visited[next]));
+        }
+    }
+    false
+}
@@ -0,0 +1,55 @@
+use crate::config::{Component, Describe};
+use crate::core::{Candidate, Id, Method, Ordinal, Profile, SingleWinner};
+use crate::methods::pairwise_preferences;
+
+/// A single-winner, ranked, Condorcet-consistent voting method (also known as the beatpath method).
+///
+/// The strength of a path between two candidates is the strength of its weakest pairwise victory, and the winner is the candidate whose strongest path to every other candidate is at least as strong as the strongest path back.
+///
+/// Pairwise victories are measured by winning votes. Unranked candidates are considered tied below all ranked candidates.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Schulze;
+
+impl Method for Schulze {
+    type Ballot = Ordinal;
+    type Winner = SingleWinner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        let n = candidates.len();
+        let d = pairwise_preferences(candidates, &profile);
+
+        // Direct links are the pairwise victories (by winning votes).
+        let mut p = vec![vec![0usize; n]; n];
+        for i in 0..n {
+            for j in (0..n).filter(|&j| j != i) {
+                if d[i][j] > d[j][i] {
+                    p[i][j] = d[i][j];
+                }
+            }
+        }
+
+        // Strongest paths (widest path variant of Floyd-Warshall).
+        for k in 0..n {
+            for i in (0..n).filter(|&i| i != k) {
+                for j in (0..n).filter(|&j| j != k && j != i) {
+                    p[i][j] = p[i][j].max(p[i][k].min(p[k][j]));
+                }
+            }
+        }
+
+        let winners: Vec<Id> = (0..n)
+            .filter(|&i| (0..n).all(|j| p[i][j] >= p[j][i]))
+            .collect();
+        match winners.len() {
+            0 => SingleWinner::none(),
+            1 => SingleWinner::win(candidates, winners[0]),
+            _ => SingleWinner::tie(candidates, &winners),
+        }
+    }
+}
+
+impl Describe for Schulze {
+    fn describe(&self) -> Component {
+        Component::new("schulze")
+    }
+}
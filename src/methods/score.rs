@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::{Component, Describe};
+use crate::core::{Candidate, Cardinal, Id, Method, Profile, SingleWinner};
+use crate::methods::find_candidates_with_value;
+
+/// A single-winner, cardinal voting method (also known as range voting). Each voter scores the candidates, and the candidate with the highest total or average score wins.
+///
+/// Candidates missing from a ballot are unscored by that voter. When totaling, unscored candidates receive a score of zero. When averaging, only the voters that scored a candidate are counted, and candidates scored by fewer than `quorum` voters cannot win.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Score {
+    /// The candidate with the highest total score wins.
+    Total,
+    /// The eligible candidate with the highest average score wins.
+    Average {
+        /// The minimum number of voters that must score a candidate for them to be eligible.
+        quorum: usize,
+    },
+}
+
+impl Method for Score {
+    type Ballot = Cardinal;
+    type Winner = SingleWinner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        let (totals, counts) = profile.iter().fold(
+            (
+                vec![0usize; candidates.len()],
+                vec![0usize; candidates.len()],
+            ),
+            |(mut totals, mut counts), b| {
+                for (id, score) in b.iter() {
+                    totals[*id] += score;
+                    counts[*id] += 1;
+                }
+                (totals, counts)
+            },
+        );
+
+        let winners: Vec<Id> = match self {
+            Self::Total => {
+                let max_total = totals.iter().max().copied().unwrap_or(0);
+                find_candidates_with_value(&totals, &max_total)
+            }
+            Self::Average { quorum } => {
+                let eligible: Vec<Id> = (0..candidates.len())
+                    .filter(|&c| counts[c] > 0 && counts[c] >= *quorum)
+                    .collect();
+                // Compare averages `totals[a] / counts[a]` and `totals[b] / counts[b]` without rounding.
+                let best = eligible
+                    .iter()
+                    .copied()
+                    .max_by(|&a, &b| (totals[a] * counts[b]).cmp(&(totals[b] * counts[a])));
+                best.map_or_else(Vec::new, |best| {
+                    eligible
+                        .into_iter()
+                        .filter(|&c| totals[c] * counts[best] == totals[best] * counts[c])
+                        .collect()
+                })
+            }
+        };
+        match winners.len() {
+            0 => SingleWinner::none(),
+            1 => SingleWinner::win(candidates, winners[0]),
+            _ => SingleWinner::tie(candidates, &winners),
+        }
+    }
+}
+
+impl Describe for Score {
+    fn describe(&self) -> Component {
+        match self {
+            Self::Total => Component::new("score"),
+            Self::Average { quorum } => Component::new("score").param("quorum", *quorum),
+        }
+    }
+}
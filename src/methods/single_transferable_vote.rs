@@ -1,18 +1,66 @@
-use ordered_float::OrderedFloat;
+use serde::{Deserialize, Serialize};
 
-use crate::core::{Candidate, Id, Method, MultiWinner, Ordinal, Profile};
+use crate::config::{Component, Describe};
+use crate::core::{Candidate, Id, Method, MultiWinner, Ordinal, Profile, Ties};
+use crate::methods::{Quota, as_f64};
 
-/// A multi-winner, ranked voting method. Candidates with the fewest votes are eliminated in each round, and their votes are transferred to the next preference. This process continues until candidates achieve a required quota or all positions are filled.
-#[derive(Debug, Clone, serde::Serialize)]
+/// A multi-winner, ranked voting method.
+///
+/// Candidates with the fewest votes are eliminated in each round, and their votes are transferred to the next preference. This process continues until candidates achieve a required quota or all positions are filled.
+///
+/// Each ballot carries a transfer weight, starting at one. When a candidate reaches the quota, their surplus is transferred by reducing the weight of the ballots counting for them, and when a candidate is excluded, their ballots are transferred at their current weight. Transfers skip candidates that are already elected or excluded. The counting rule determines exactly how surpluses are transferred.
+///
+/// When several candidates are tied for exclusion, the tie-breaker decides which of them is excluded. If it cannot tell them apart, the one with the fewest votes in the most recent round where they differed is excluded (falling back to the highest candidate id).
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct STV {
     seats: usize,
+    rule: TransferRule,
+    quota: Quota,
+}
+
+/// The rule used to transfer surplus votes in STV.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TransferRule {
+    /// Weighted Inclusive Gregory: all ballots counting for an elected candidate are transferred, with their weights multiplied by `surplus / votes`.
+    WeightedInclusiveGregory,
+    /// Scottish STV: Weighted Inclusive Gregory with transfer weights truncated to five decimal places.
+    Scottish,
+    /// Meek: elected candidates keep only a fraction of every ballot that reaches them, passing the rest on. The fractions are iterated until every elected candidate holds exactly a quota, which is recomputed as ballots exhaust. The Droop quota is not rounded, but is `votes / (seats + 1)` plus a small epsilon. Excluded candidates are skipped as if they never stood.
+    Meek,
 }
 
 impl STV {
     /// Creates a new instance of STV with the specified number of seats.
+    ///
+    /// This counts with Weighted Inclusive Gregory and the Droop quota.
     #[must_use]
     pub const fn new(seats: usize) -> Self {
-        Self { seats }
+        Self::with_rule(seats, TransferRule::WeightedInclusiveGregory)
+    }
+    /// Creates a new instance of STV with the specified number of seats and transfer rule, using the Droop quota.
+    #[must_use]
+    pub const fn with_rule(seats: usize, rule: TransferRule) -> Self {
+        Self {
+            seats,
+            rule,
+            quota: Quota::Droop,
+        }
+    }
+    /// Creates a new instance of Scottish STV with the specified number of seats.
+    #[must_use]
+    pub const fn scottish(seats: usize) -> Self {
+        Self::with_rule(seats, TransferRule::Scottish)
+    }
+    /// Creates a new instance of Meek STV with the specified number of seats.
+    #[must_use]
+    pub const fn meek(seats: usize) -> Self {
+        Self::with_rule(seats, TransferRule::Meek)
+    }
+    /// Sets the quota.
+    #[must_use]
+    pub const fn quota(mut self, quota: Quota) -> Self {
+        self.quota = quota;
+        self
     }
 }
 
@@ -21,69 +69,241 @@ impl Method for STV {
     type Winner = MultiWinner;
     #[inline]
     fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
-        let mut ballots: Vec<Self::Ballot> = profile.into_iter().collect();
-        let mut winners: Vec<Id> = vec![];
-        let droop_quota = OrderedFloat((ballots.len() / (self.seats + 1) + 1) as f64);
-
-        let mut vote_shares = profile.iter().fold(
-            vec![OrderedFloat(0.0); candidates.len()],
-            |mut counts, b| {
-                if let Some(first_place_candidate) = b.first() {
-                    counts[*first_place_candidate] += OrderedFloat(1.0);
+        self.outcome_with(candidates, profile, &mut Ties::none())
+    }
+    fn outcome_with(
+        &self,
+        candidates: &[Candidate],
+        profile: Profile<Self::Ballot>,
+        ties: &mut Ties,
+    ) -> Self::Winner {
+        let mut count = Count::new(candidates, self.seats);
+        match self.rule {
+            TransferRule::WeightedInclusiveGregory | TransferRule::Scottish => {
+                self.gregory_count(&mut count, &profile, ties);
+            }
+            TransferRule::Meek => self.meek_count(&mut count, &profile, ties),
+        }
+        if count.elected.is_empty() {
+            return MultiWinner::none();
+        }
+        MultiWinner::seats(candidates, &count.elected)
+    }
+}
+
+impl Describe for STV {
+    fn describe(&self) -> Component {
+        let rule = match self.rule {
+            TransferRule::WeightedInclusiveGregory => "wig",
+            TransferRule::Scottish => "scottish",
+            TransferRule::Meek => "meek",
+        };
+        Component::new("stv")
+            .param("seats", self.seats)
+            .param("rule", rule)
+            .param("quota", self.quota.name())
+    }
+}
+
+impl STV {
+    /// Counts with the (Weighted Inclusive) Gregory method of transferring surpluses.
+    fn gregory_count(&self, count: &mut Count, profile: &Profile<Ordinal>, ties: &mut Ties) {
+        let mut ballots: Vec<(&[Id], f64)> = profile.iter().map(|b| (&b[..], 1.0)).collect();
+        let quota = self.quota.threshold(as_f64(profile.len()), self.seats);
+        let mut surpluses: Vec<Id> = Vec::new();
+
+        loop {
+            // Count the ballots at their current preference.
+            let mut votes = vec![0.0; count.hopeful.len()];
+            for (b, weight) in &mut ballots {
+                *b = skip_to_continuing(b, &count.hopeful, &count.elected, &surpluses);
+                if let Some(c) = b.first() {
+                    votes[*c] += *weight;
+                }
+            }
+            count.history.push(votes.clone());
+            ties.record(votes.iter().copied());
+            if count.is_finished(&votes) {
+                break;
+            }
+
+            // Elect every candidate that reached the quota, and queue their surpluses.
+            for c in count.over_quota(&votes, quota) {
+                count.elect(c);
+                surpluses.push(c);
+            }
+            if count.elected.len() >= self.seats {
+                break;
+            }
+
+            if surpluses.is_empty() {
+                let loser = count.loser(&votes, ties);
+                count.exclude(loser);
+                continue;
+            }
+            // Transfer the largest surplus.
+            let (i, &winner) = surpluses
+                .iter()
+                .enumerate()
+                .max_by(|(_, a), (_, b)| votes[**a].total_cmp(&votes[**b]))
+                .unwrap();
+            surpluses.remove(i);
+            let factor = ((votes[winner] - quota) / votes[winner]).max(0.0);
+            for (b, weight) in &mut ballots {
+                if b.first() == Some(&winner) {
+                    *weight = match self.rule {
+                        TransferRule::Scottish => (*weight * factor * 1e5).trunc() / 1e5,
+                        _ => *weight * factor,
+                    };
+                    *b = &b[1..];
                 }
-                counts
-            },
-        );
-
-        while winners.len() < self.seats {
-            // determine elected candidates (if any)
-            let elected = vote_shares.iter().position(|&votes| votes >= droop_quota);
-            if let Some(winner) = elected {
-                // NOTE: It's fine to have one winner elected at a time, as if there are multiple winners, they will be elected one by one. If the seats fill up, then there needs to be a mechanism to select which candidates will be elected, and that mechanism is built in.
-
-                // Add winner to winners set and proportionally transfer surplus votes.
-                winners.push(winner);
-
-                let surplus = vote_shares[winner] - droop_quota;
-                vote_shares[winner] = droop_quota;
-
-                let mut second_place_proportions = vec![0usize; candidates.len()];
-                ballots
-                    .iter()
-                    .filter(|b| b.first() == Some(&winner))
-                    .for_each(|b| {
-                        let second_place = b.get(1);
-                        if let Some(second_place) = second_place {
-                            second_place_proportions[*second_place] += 1;
+            }
+        }
+    }
+
+    /// Counts with Meek's method.
+    fn meek_count(&self, count: &mut Count, profile: &Profile<Ordinal>, ties: &mut Ties) {
+        const TOLERANCE: f64 = 1e-9;
+        const ITERATIONS: usize = 1000;
+        let mut keep = vec![1.0f64; count.hopeful.len()];
+
+        loop {
+            // Iterate the keep values until every elected candidate holds exactly a quota.
+            let mut iterations = 0;
+            let (votes, quota) = loop {
+                iterations += 1;
+                let mut votes = vec![0.0; keep.len()];
+                let mut excess = 0.0;
+                for b in profile.iter() {
+                    let mut weight = 1.0;
+                    for &c in b.iter() {
+                        votes[c] += weight * keep[c];
+                        weight *= 1.0 - keep[c];
+                        if weight <= 0.0 {
+                            break;
                         }
-                    });
-                second_place_proportions
-                    .iter()
-                    .enumerate()
-                    .for_each(|(c, &count)| {
-                        vote_shares[c] += OrderedFloat(count as f64) / surplus;
-                    });
-
-                ballots.retain(|b| b.first() != Some(&winner));
-            } else {
-                // Remove candidate with fewest votes and proportionally transfer votes.
-                let min_votes = vote_shares.iter().min().unwrap();
-                let losers: Vec<usize> = vote_shares
-                    .iter()
-                    .enumerate()
-                    .filter_map(|(id, votes)| (votes == min_votes).then_some(id))
-                    .collect();
-                ballots
-                    .iter_mut()
-                    .for_each(|b| b.0.retain(|c| !losers.contains(c)));
+                    }
+                    excess += weight;
+                }
+                let quota = self
+                    .quota
+                    .fractional_threshold(as_f64(profile.len()) - excess, self.seats);
+                let mut converged = true;
+                for &c in &count.elected {
+                    let adjusted = (keep[c] * quota / votes[c]).min(1.0);
+                    if votes[c] > 0.0 && (adjusted - keep[c]).abs() > TOLERANCE {
+                        keep[c] = adjusted;
+                        converged = false;
+                    }
+                }
+                converged |= iterations >= ITERATIONS;
+                if converged {
+                    break (votes, quota);
+                }
+            };
+            count.history.push(votes.clone());
+            ties.record(votes.iter().copied());
+            if count.is_finished(&votes) {
+                break;
+            }
+
+            let elected = count.over_quota(&votes, quota - TOLERANCE);
+            if elected.is_empty() {
+                let loser = count.loser(&votes, ties);
+                count.exclude(loser);
+                keep[loser] = 0.0;
+            }
+            for c in elected {
+                count.elect(c);
+            }
+            if count.elected.len() >= self.seats {
+                break;
             }
         }
+    }
+}
+
+/// The state of an STV count.
+struct Count {
+    seats: usize,
+    /// Whether each candidate is still hopeful (neither elected nor excluded).
+    hopeful: Vec<bool>,
+    /// The elected candidates, in order of election.
+    elected: Vec<Id>,
+    /// The votes of each candidate in every round so far.
+    history: Vec<Vec<f64>>,
+}
 
-        MultiWinner::Elected(
-            winners
-                .into_iter()
-                .filter_map(|id| candidates.iter().find(|c| c.id() == id).cloned())
-                .collect(),
-        )
+impl Count {
+    fn new(candidates: &[Candidate], seats: usize) -> Self {
+        Self {
+            seats: seats.min(candidates.len()),
+            hopeful: vec![true; candidates.len()],
+            elected: Vec::new(),
+            history: Vec::new(),
+        }
+    }
+    fn hopefuls(&self) -> impl Iterator<Item = Id> + '_ {
+        (0..self.hopeful.len()).filter(|&c| self.hopeful[c])
     }
+    fn elect(&mut self, c: Id) {
+        self.hopeful[c] = false;
+        self.elected.push(c);
+    }
+    fn exclude(&mut self, c: Id) {
+        self.hopeful[c] = false;
+    }
+    /// Whether the count is over, which is also the case when the remaining hopefuls can fill the remaining seats (in which case they are elected).
+    fn is_finished(&mut self, votes: &[f64]) -> bool {
+        if self.elected.len() >= self.seats {
+            return true;
+        }
+        let mut hopefuls: Vec<Id> = self.hopefuls().collect();
+        if self.elected.len() + hopefuls.len() > self.seats {
+            return false;
+        }
+        hopefuls.sort_by(|a, b| votes[*b].total_cmp(&votes[*a]));
+        for c in hopefuls {
+            self.elect(c);
+        }
+        true
+    }
+    /// The hopefuls that reached the quota, from most to fewest votes, up to the number of seats remaining.
+    fn over_quota(&self, votes: &[f64], quota: f64) -> Vec<Id> {
+        let mut elected: Vec<Id> = self.hopefuls().filter(|&c| votes[c] >= quota).collect();
+        elected.sort_by(|a, b| votes[*b].total_cmp(&votes[*a]));
+        elected.truncate(self.seats - self.elected.len());
+        elected
+    }
+    /// The hopeful with the fewest votes, breaking ties with the tie-breaker, and then by the most recent round in which the tied candidates differed.
+    fn loser(&self, votes: &[f64], ties: &mut Ties) -> Id {
+        let fewest = self
+            .hopefuls()
+            .map(|c| votes[c])
+            .fold(f64::INFINITY, f64::min);
+        let losers: Vec<Id> = self.hopefuls().filter(|&c| votes[c] <= fewest).collect();
+        let mut losers = ties.worst(&losers);
+        for round in self.history.iter().rev() {
+            let fewest = losers
+                .iter()
+                .map(|&c| round[c])
+                .fold(f64::INFINITY, f64::min);
+            losers.retain(|&c| round[c] <= fewest);
+        }
+        losers.last().copied().unwrap_or(0)
+    }
+}
+
+/// Skips past candidates that can no longer receive votes, except for elected candidates whose surplus is yet to be transferred.
+fn skip_to_continuing<'a>(
+    ballot: &'a [Id],
+    hopeful: &[bool],
+    elected: &[Id],
+    surpluses: &[Id],
+) -> &'a [Id] {
+    let start = ballot
+        .iter()
+        .position(|c| hopeful[*c] || (surpluses.contains(c) && elected.contains(c)))
+        .unwrap_or(ballot.len());
+    &ballot[start..]
 }
@@ -0,0 +1,138 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::config::{Component, Describe};
+use crate::core::{Candidate, Id, Method, Ordinal, Outcome, Profile, Ties};
+use crate::methods::pairwise_preferences;
+
+/// A Condorcet-completion combinator for ranked methods.
+///
+/// The ballots are restricted to the Smith set (or the Schwartz set), and the wrapped method determines the outcome, e.g. Smith//IRV or Smith//Plurality.
+///
+/// The wrapped method is only given the candidates in the set, so it cannot elect a candidate outside of it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SmithRestricted<M: Method<Ballot = Ordinal>> {
+    method: M,
+    set: DominantSet,
+}
+
+/// The set of candidates that a Condorcet-completion method is restricted to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DominantSet {
+    /// The smallest non-empty set of candidates that each beat every candidate outside the set pairwise.
+    Smith,
+    /// The union of the minimal sets of candidates that are unbeaten by every candidate outside the set. This is a subset of the Smith set, and differs from it only when there are pairwise ties.
+    Schwartz,
+}
+
+impl<M: Method<Ballot = Ordinal>> SmithRestricted<M> {
+    /// Creates a new instance of the method restricted to the Smith set.
+    #[must_use]
+    pub const fn new(method: M) -> Self {
+        Self {
+            method,
+            set: DominantSet::Smith,
+        }
+    }
+    /// Creates a new instance of the method restricted to the Schwartz set.
+    #[must_use]
+    pub const fn schwartz(method: M) -> Self {
+        Self {
+            method,
+            set: DominantSet::Schwartz,
+        }
+    }
+}
+
+impl<M: Method<Ballot = Ordinal>> Method for SmithRestricted<M> {
+    type Ballot = Ordinal;
+    type Winner = M::Winner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        self.outcome_with(candidates, profile, &mut Ties::none())
+    }
+    fn outcome_with(
+        &self,
+        candidates: &[Candidate],
+        profile: Profile<Self::Ballot>,
+        ties: &mut Ties,
+    ) -> Self::Winner {
+        let remaining: Vec<Id> = candidates.iter().map(Candidate::id).collect();
+        let set = dominant_set(candidates, &profile, &remaining, self.set);
+        // the wrapped method counts the candidates in the set as candidates 0..k
+        let mut index = vec![None; candidates.len()];
+        for (i, &c) in set.iter().enumerate() {
+            index[c] = Some(i);
+        }
+        let restricted: Profile<Ordinal> = profile
+            .iter()
+            .map(|b| Ordinal(b.iter().filter_map(|&c| index[c]).collect()))
+            .filter(|b| !b.is_empty())
+            .collect();
+        let original: Vec<Candidate> = set
+            .iter()
+            .filter_map(|&c| candidates.iter().find(|candidate| candidate.id() == c))
+            .cloned()
+            .collect();
+        let relabelled: Vec<Candidate> = original
+            .iter()
+            .enumerate()
+            .map(|(i, c)| c.with_id(i))
+            .collect();
+        self.method
+            .outcome_with(&relabelled, restricted, &mut ties.restrict(&set))
+            .relabel(&original)
+    }
+}
+
+impl<M: Method<Ballot = Ordinal> + Describe> Describe for SmithRestricted<M> {
+    fn describe(&self) -> Component {
+        let name = match self.set {
+            DominantSet::Smith => "smith",
+            DominantSet::Schwartz => "schwartz",
+        };
+        Component::new(name).param("method", json!(self.method.describe()))
+    }
+}
+
+/// Computes the Smith or Schwartz set of the remaining candidates, in order of candidate id.
+pub fn dominant_set(
+    candidates: &[Candidate],
+    ballots: &[Ordinal],
+    remaining: &[Id],
+    set: DominantSet,
+) -> Vec<Id> {
+    let d = pairwise_preferences(candidates, ballots);
+    let n = remaining.len();
+
+    // reaches[i][j] is whether there is a path from i to j in the dominance relation.
+    let mut reaches: Vec<Vec<bool>> = remaining
+        .iter()
+        .map(|&a| {
+            remaining
+                .iter()
+                .map(|&b| match set {
+                    DominantSet::Smith => d[a][b] >= d[b][a],
+                    DominantSet::Schwartz => d[a][b] > d[b][a],
+                })
+                .collect()
+        })
+        .collect();
+    for k in 0..n {
+        for i in 0..n {
+            for j in 0..n {
+                reaches[i][j] = reaches[i][j] || (reaches[i][k] && reaches[k][j]);
+            }
+        }
+    }
+
+    let mut members: Vec<Id> = (0..n)
+        .filter(|&i| match set {
+            DominantSet::Smith => (0..n).all(|j| i == j || reaches[i][j]),
+            DominantSet::Schwartz => (0..n).all(|j| !reaches[j][i] || reaches[i][j]),
+        })
+        .map(|i| remaining[i])
+        .collect();
+    members.sort_unstable();
+    members
+}
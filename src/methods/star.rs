@@ -1,7 +1,11 @@
-use crate::core::{Candidate, Cardinal, Method, Profile, SingleWinner};
+use crate::config::{Component, Describe};
+use crate::core::{Candidate, Cardinal, Id, Method, Profile, SingleWinner, Ties};
+use crate::methods::as_f64;
 
 /// A single-winner, cardinal voting method. The two candidates with the highest scores advance to a runoff, where the candidate with the most votes in the runoff wins.
-#[derive(Debug, Clone, serde::Serialize)]
+///
+/// Candidates tied for a place in the runoff are decided by the tie-breaker, falling back to the lowest candidate id.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Star;
 
 impl Method for Star {
@@ -9,6 +13,14 @@ impl Method for Star {
     type Winner = SingleWinner;
     #[inline]
     fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        self.outcome_with(candidates, profile, &mut Ties::none())
+    }
+    fn outcome_with(
+        &self,
+        candidates: &[Candidate],
+        profile: Profile<Self::Ballot>,
+        ties: &mut Ties,
+    ) -> Self::Winner {
         // Score candidates
         let cumulative_scores = profile.iter().fold(
             vec![0usize; candidates.len()],
@@ -19,20 +31,15 @@ impl Method for Star {
                 cumulative_scores
             },
         );
+        ties.record(cumulative_scores.iter().copied().map(as_f64));
 
-        let (mut first_place_score, mut second_place_score) = (0, 0);
-        let (mut first_place, mut second_place) = (0, 0);
-        for (i, score) in cumulative_scores.iter().enumerate() {
-            if *score > first_place_score {
-                second_place_score = first_place_score;
-                second_place = first_place;
-                first_place_score = *score;
-                first_place = i;
-            } else if *score > second_place_score {
-                second_place_score = *score;
-                second_place = i;
-            }
-        }
+        let all: Vec<Id> = (0..candidates.len()).collect();
+        let ranking = ties.rank(&all, |c| cumulative_scores[c]).concat();
+        let (first_place, second_place) = match ranking[..] {
+            [] => return SingleWinner::none(),
+            [only] => return SingleWinner::win(candidates, only),
+            [first, second, ..] => (first, second),
+        };
 
         // Instant runoff (tally who has the most wins (higher placements))
         let (first_tally, second_tally) = profile.iter().fold((0, 0), |(c1, c2), preference| {
@@ -58,3 +65,9 @@ impl Method for Star {
         }
     }
 }
+
+impl Describe for Star {
+    fn describe(&self) -> Component {
+        Component::new("star")
+    }
+}
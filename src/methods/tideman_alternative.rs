@@ -0,0 +1,57 @@
+use crate::config::{Component, Describe};
+use crate::core::{Candidate, Id, Method, Ordinal, Profile, SingleWinner, Ties};
+use crate::methods::as_f64;
+use crate::methods::instant_runoff::{Round, eliminate, first_place_counts};
+use crate::methods::smith_restricted::{DominantSet, dominant_set};
+
+/// A single-winner, ranked, Condorcet-consistent voting method (Tideman's Alternative). Restriction to the Smith set alternates with IRV elimination.
+///
+/// In each round, every remaining candidate outside of the Smith set (of the remaining candidates) is eliminated. If all of them are in the Smith set, the candidate(s) with the fewest first-place votes are eliminated instead (only one of them, if a tie-breaker picks it). This continues until the Smith set is a single candidate.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct TidemanAlternative;
+
+impl Method for TidemanAlternative {
+    type Ballot = Ordinal;
+    type Winner = SingleWinner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        self.outcome_with(candidates, profile, &mut Ties::none())
+    }
+    fn outcome_with(
+        &self,
+        candidates: &[Candidate],
+        profile: Profile<Self::Ballot>,
+        ties: &mut Ties,
+    ) -> Self::Winner {
+        eliminate(candidates, &profile, ties, |ballots, remaining, ties| {
+            let smith = dominant_set(candidates, ballots, remaining, DominantSet::Smith);
+            if smith.len() == 1 {
+                return Round::Elect(smith);
+            }
+            if smith.len() < remaining.len() {
+                return Round::Eliminate(
+                    remaining
+                        .iter()
+                        .copied()
+                        .filter(|c| !smith.contains(c))
+                        .collect(),
+                );
+            }
+            let fpc = first_place_counts(candidates, ballots);
+            ties.record(fpc.iter().copied().map(as_f64));
+            let min_first_place_votes = remaining.iter().map(|&c| fpc[c]).min().unwrap_or(0);
+            let losers: Vec<Id> = remaining
+                .iter()
+                .copied()
+                .filter(|&c| fpc[c] == min_first_place_votes)
+                .collect();
+            Round::EliminateOne(losers)
+        })
+    }
+}
+
+impl Describe for TidemanAlternative {
+    fn describe(&self) -> Component {
+        Component::new("tideman_alternative")
+    }
+}
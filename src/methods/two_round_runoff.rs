@@ -0,0 +1,107 @@
+use crate::config::{Component, Describe};
+use crate::core::{Candidate, Id, Method, Ordinal, Profile, SingleWinner, Ties};
+use crate::methods::as_f64;
+use crate::methods::instant_runoff::first_place_counts;
+
+/// A single-winner, ranked voting method (also known as the contingent vote).
+///
+/// If no candidate has a majority of first-place votes, the two candidates with the most first-place votes advance to a head-to-head runoff, where each ballot counts for whichever finalist it ranks higher.
+///
+/// If several candidates are tied for a place in the runoff, they all advance, unless a tie-breaker decides which of them does.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct TwoRoundRunoff;
+
+/// A single-winner, ranked voting method. Like [`TwoRoundRunoff`], but only the first two preferences on each ballot are counted, so a ballot that ranks neither finalist in its first two preferences is exhausted in the runoff.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SupplementaryVote;
+
+impl Method for TwoRoundRunoff {
+    type Ballot = Ordinal;
+    type Winner = SingleWinner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        self.outcome_with(candidates, profile, &mut Ties::none())
+    }
+    fn outcome_with(
+        &self,
+        candidates: &[Candidate],
+        profile: Profile<Self::Ballot>,
+        ties: &mut Ties,
+    ) -> Self::Winner {
+        runoff(candidates, &profile, usize::MAX, ties)
+    }
+}
+
+impl Describe for TwoRoundRunoff {
+    fn describe(&self) -> Component {
+        Component::new("two_round_runoff")
+    }
+}
+
+impl Method for SupplementaryVote {
+    type Ballot = Ordinal;
+    type Winner = SingleWinner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        self.outcome_with(candidates, profile, &mut Ties::none())
+    }
+    fn outcome_with(
+        &self,
+        candidates: &[Candidate],
+        profile: Profile<Self::Ballot>,
+        ties: &mut Ties,
+    ) -> Self::Winner {
+        runoff(candidates, &profile, 2, ties)
+    }
+}
+
+impl Describe for SupplementaryVote {
+    fn describe(&self) -> Component {
+        Component::new("supplementary_vote")
+    }
+}
+
+/// Runs a first round on first-place votes and, if nobody has a majority, a runoff between the top two candidates counting only the first `depth` preferences of each ballot.
+fn runoff(
+    candidates: &[Candidate],
+    profile: &Profile<Ordinal>,
+    depth: usize,
+    ties: &mut Ties,
+) -> SingleWinner {
+    let majority = profile.len() / 2 + 1;
+    let fpc = first_place_counts(candidates, profile);
+    ties.record(fpc.iter().copied().map(as_f64));
+
+    if let Some(winner) = fpc.iter().position(|&x| x >= majority) {
+        return SingleWinner::win(candidates, winner);
+    }
+
+    // Advance the top two candidates (and any tied with them that the tie-breaker cannot tell apart).
+    let contenders: Vec<Id> = (0..candidates.len()).filter(|&c| fpc[c] > 0).collect();
+    let mut finalists: Vec<Id> = Vec::new();
+    for group in ties.rank(&contenders, |c| fpc[c]) {
+        if finalists.len() >= 2 {
+            break;
+        }
+        finalists.extend(group);
+    }
+
+    let runoff_counts = profile
+        .iter()
+        .fold(vec![0usize; candidates.len()], |mut counts, b| {
+            if let Some(finalist) = b.iter().take(depth).find(|c| finalists.contains(c)) {
+                counts[*finalist] += 1;
+            }
+            counts
+        });
+    let max_count = finalists.iter().map(|&c| runoff_counts[c]).max();
+    let winners: Vec<Id> = finalists
+        .into_iter()
+        .filter(|&c| Some(runoff_counts[c]) == max_count)
+        .collect();
+    match winners.len() {
+        0 => SingleWinner::none(),
+        1 => SingleWinner::win(candidates, winners[0]),
+        _ => SingleWinner::tie(candidates, &winners),
+    }
+}
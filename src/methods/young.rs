@@ -0,0 +1,150 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::{Component, Describe};
+use crate::core::{Candidate, Id, Method, Ordinal, Profile, SingleWinner};
+use crate::methods::dodgson::tideman_scores;
+use crate::methods::{MethodError, SEARCH_LIMIT};
+
+/// A single-winner, ranked, Condorcet-consistent voting method.
+///
+/// The Young score of a candidate is the fewest voters that need to be removed from the election for them to beat every other candidate pairwise, and the candidate with the lowest score wins.
+///
+/// Computing Young scores is NP-hard. They are found exactly by dynamic programming over the distinct ways a ballot can rank the candidate against each opponent, which is fast for elections with few candidates and voters. The number of states grows with both, so past the candidate limit (6 by default), or when the search would visit more than a million states, the outcome falls back to Tideman's approximation (as for [`Dodgson`](crate::methods::Dodgson)), where a candidate's score is the sum of their pairwise defeat margins.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Young {
+    limit: usize,
+}
+
+impl Young {
+    /// Creates a new instance of Young that solves elections with up to `limit` candidates exactly.
+    #[must_use]
+    pub const fn new(limit: usize) -> Self {
+        Self { limit }
+    }
+    /// Computes the Young score of each candidate exactly, or `None` for candidates that no removal of voters makes a Condorcet winner.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are more candidates than the limit, or the search visits too many states.
+    pub fn scores(
+        &self,
+        candidates: &[Candidate],
+        profile: &Profile<Ordinal>,
+    ) -> Result<Vec<Option<usize>>, MethodError> {
+        if candidates.len() > self.limit {
+            return Err(MethodError::TooManyCandidates {
+                found: candidates.len(),
+                limit: self.limit,
+            });
+        }
+        (0..candidates.len())
+            .map(|c| young_score(candidates.len(), profile, c))
+            .collect()
+    }
+}
+
+impl Default for Young {
+    fn default() -> Self {
+        Self::new(6)
+    }
+}
+
+impl Method for Young {
+    type Ballot = Ordinal;
+    type Winner = SingleWinner;
+    #[inline]
+    fn outcome(&self, candidates: &[Candidate], profile: Profile<Self::Ballot>) -> Self::Winner {
+        let scores = self
+            .scores(candidates, &profile)
+            .unwrap_or_else(|_| tideman_scores(candidates, &profile));
+        let Some(min_score) = scores.iter().flatten().min() else {
+            return SingleWinner::none();
+        };
+        let winners: Vec<Id> = (0..candidates.len())
+            .filter(|&c| scores[c] == Some(*min_score))
+            .collect();
+        match winners.len() {
+            0 => SingleWinner::none(),
+            1 => SingleWinner::win(candidates, winners[0]),
+            _ => SingleWinner::tie(candidates, &winners),
+        }
+    }
+}
+
+impl Describe for Young {
+    fn describe(&self) -> Component {
+        Component::new("young").param("limit", self.limit)
+    }
+}
+
+/// Computes the Young score of `c` exactly.
+fn young_score(n: usize, ballots: &[Ordinal], c: Id) -> Result<Option<usize>, MethodError> {
+    // Group the ballots by how they rank `c` against each opponent: 1 if above, -1 if below, and 0 if tied.
+    let mut types: BTreeMap<Vec<isize>, usize> = BTreeMap::new();
+    for b in ballots {
+        let rank = |candidate: Id| b.iter().position(|&other| other == candidate);
+        let signs: Vec<isize> = (0..n)
+            .map(|j| match (rank(c), rank(j)) {
+                _ if j == c => 0,
+                (Some(a), Some(b)) if a < b => 1,
+                (Some(_), None) => 1,
+                (_, Some(_)) => -1,
+                (None, None) => 0,
+            })
+            .collect();
+        *types.entry(signs).or_insert(0) += 1;
+    }
+    let types: Vec<(Vec<isize>, usize)> = types.into_iter().collect();
+
+    // The total support for and against `c` against each opponent in the types yet to be processed.
+    let remaining = |from: usize, sign: isize| -> Vec<isize> {
+        (0..n)
+            .map(|j| {
+                types[from..]
+                    .iter()
+                    .filter(|(signs, _)| signs[j] == sign)
+                    .map(|(_, count)| count.cast_signed())
+                    .sum()
+            })
+            .collect()
+    };
+
+    // The most voters that can be kept while reaching each vector of margins.
+    let mut kept: HashMap<Vec<isize>, usize> = HashMap::from([(vec![0; n], 0)]);
+    let mut visited = 0;
+    for (i, (signs, count)) in types.iter().enumerate() {
+        visited += kept.len() * (count + 1);
+        if visited > SEARCH_LIMIT {
+            return Err(MethodError::TooManyStates {
+                limit: SEARCH_LIMIT,
+            });
+        }
+        let (support, opposition) = (remaining(i + 1, 1), remaining(i + 1, -1));
+        let mut next: HashMap<Vec<isize>, usize> = HashMap::with_capacity(kept.len());
+        for (margins, voters) in kept {
+            for k in 0..=*count {
+                let margins: Vec<isize> = (0..n)
+                    .map(|j| {
+                        // Margins that no later type can bring below one are equivalent.
+                        (margins[j] + k.cast_signed() * signs[j]).min(1 + opposition[j])
+                    })
+                    .collect();
+                // Skip margins that no later type can bring up to one.
+                if (0..n).any(|j| j != c && margins[j] + support[j] < 1) {
+                    continue;
+                }
+                let best = next.entry(margins).or_insert(0);
+                *best = (*best).max(voters + k);
+            }
+        }
+        kept = next;
+    }
+    let voters: usize = types.iter().map(|(_, count)| count).sum();
+    Ok(kept
+        .into_iter()
+        .filter(|(margins, _)| (0..n).all(|j| j == c || margins[j] >= 1))
+        .map(|(_, k)| voters - k)
+        .min())
+}
@@ -1,7 +1,8 @@
+use crate::config::{Component, Describe};
 use crate::core::{Candidate, Cardinal, Id, Nominal, Ordinal, Preference};
 
 use rand::{Rng, rngs::StdRng};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// The `Impartial` preference model generates rankings by assuming that all possible rankings are equally likely, leading to a uniform random distribution over all permutations.
 ///
@@ -22,7 +23,7 @@ use serde::Serialize;
 /// - The `Impartial` model represents a completely neutral preference structure.
 /// - It is often used as a baseline for analyzing voting systems.
 /// - As `n` increases, the number of possible rankings grows factorially, making exhaustive enumeration infeasible for large values of `n`.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Impartial;
 
 impl Impartial {
@@ -75,3 +76,9 @@ impl Preference<Nominal> for Impartial {
         )
     }
 }
+
+impl Describe for Impartial {
+    fn describe(&self) -> Component {
+        Component::new("impartial")
+    }
+}
@@ -1,7 +1,8 @@
+use crate::config::{Component, Describe};
 use crate::core::{Candidate, Id, Ordinal, Preference};
 
 use rand::{distr::weighted::WeightedIndex, prelude::*, rngs::StdRng};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// The Mallows model
 ///
@@ -25,7 +26,7 @@ use serde::Serialize;
 /// - `phi = 0`: The distribution is uniform over all permutations.
 /// - `phi > 0`: Higher probability is assigned to permutations closer to `pi_0`.
 /// - Larger `phi` values result in stronger adherence to `pi_0`, while smaller values allow for more variation.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Mallows {
     pi_0: Vec<Id>,
     phi: f64,
@@ -55,6 +56,14 @@ impl Preference<Ordinal> for Mallows {
     }
 }
 
+impl Describe for Mallows {
+    fn describe(&self) -> Component {
+        Component::new("mallows")
+            .param("reference", self.pi_0.clone())
+            .param("phi", self.phi)
+    }
+}
+
 /// Sample a Kendall tau distance using the Mallows model.
 fn sample_kendall_tau_distance(n: u32, phi: f64, rng: &mut StdRng) -> usize {
     let max_inv = n * (n - 1) / 2; // maximum possible inversions
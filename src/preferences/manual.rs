@@ -1,7 +1,9 @@
+use crate::config::{Component, Describe};
 use crate::core::{Ballot, Candidate, Preference, Profile};
 
 use rand::{Rng, rngs::StdRng};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
 
 /// Manual Preference Model
 ///
@@ -24,7 +26,8 @@ use serde::Serialize;
 /// - This method ensures that generated preferences are grounded in empirical data.
 /// - Unlike Mallows or Plackett-Luce, `Manual` does not impose a probabilistic structure, making it suitable for scenarios where real-world rankings are preferred.
 /// - If the dataset is small, rankings may be repeated frequently in generated samples.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(bound = "")]
 pub struct Manual<B: Ballot> {
     votes: Profile<B>,
 }
@@ -43,3 +46,9 @@ impl<B: Ballot> Preference<B> for Manual<B> {
         self.votes[rng.random_range(0..self.votes.len())].to_owned()
     }
 }
+
+impl<B: Ballot> Describe for Manual<B> {
+    fn describe(&self) -> Component {
+        Component::new("manual").param("ballots", json!(self.votes))
+    }
+}
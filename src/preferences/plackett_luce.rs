@@ -1,7 +1,9 @@
+use crate::config::{Component, Describe};
 use crate::core::{Candidate, Id, Ordinal, Preference};
 
 use rand::{distr::weighted::WeightedIndex, prelude::*};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
 
 /// The Plackett-Luce model is a probabilistic model for generating preference rankings based on candidates' weights.
 ///
@@ -28,7 +30,7 @@ use serde::Serialize;
 /// - Higher weights correspond to higher chances of being ranked earlier.
 /// - The model naturally handles different numbers of candidates and allows flexible weight assignments.
 /// - If all candidates have equal weights, the ranking is uniformly random.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlackettLuce {
     weights: Vec<(Id, f32)>,
 }
@@ -56,3 +58,9 @@ impl Preference<Ordinal> for PlackettLuce {
         Ordinal(ballot)
     }
 }
+
+impl Describe for PlackettLuce {
+    fn describe(&self) -> Component {
+        Component::new("plackett_luce").param("weights", json!(self.weights))
+    }
+}
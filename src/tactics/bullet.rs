@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::{Component, Describe};
+use crate::core::{Cardinal, Id, Nominal, Ordinal, Tactic};
+
+/// The bullet voting tactic supports only the voter's favorite candidate.
+///
+/// Support is withheld from every other candidate so that it cannot help them beat the favorite. Ranked ballots are truncated to their favorite, scored ballots score every other candidate zero, and approval ballots keep only the approval of their favorite.
+///
+/// The favorite is the first of the given candidates that the ballot supports (ranks, approves of, or scores above zero), and ballots that support none of them are left unchanged. Without any given candidates, the favorite of a ranked ballot is its first candidate, and that of a scored ballot is its highest scored candidate (with ties broken in favor of the lowest candidate id). Approval ballots do not say which approved candidate is the favorite, so they are left unchanged unless candidates are given.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Bullet {
+    favorites: Vec<Id>,
+}
+
+impl Bullet {
+    /// Instantiates a new Bullet tactic that votes for the first of the given candidates that each ballot supports.
+    #[must_use]
+    pub const fn new(favorites: Vec<Id>) -> Self {
+        Self { favorites }
+    }
+    /// The first of the given candidates that a ballot supports.
+    fn favorite(&self, supports: impl Fn(Id) -> bool) -> Option<Id> {
+        self.favorites.iter().copied().find(|&c| supports(c))
+    }
+}
+
+impl Tactic<Nominal> for Bullet {
+    #[inline]
+    fn apply(&self, ballot: Nominal) -> Nominal {
+        let mut approvals = ballot;
+        if let Some(favorite) = self.favorite(|c| approvals.contains(&c)) {
+            approvals.retain(|&c| c == favorite);
+        }
+        approvals
+    }
+}
+
+impl Tactic<Ordinal> for Bullet {
+    #[inline]
+    fn apply(&self, ballot: Ordinal) -> Ordinal {
+        if self.favorites.is_empty() {
+            let mut ranking = ballot;
+            ranking.truncate(1);
+            return ranking;
+        }
+        self.favorite(|c| ballot.contains(&c))
+            .map_or(ballot, |favorite| Ordinal(vec![favorite]))
+    }
+}
+
+impl Tactic<Cardinal> for Bullet {
+    #[inline]
+    fn apply(&self, ballot: Cardinal) -> Cardinal {
+        let favorite = if self.favorites.is_empty() {
+            ballot
+                .iter()
+                .fold(
+                    None,
+                    |best: Option<(usize, usize)>, (&c, &score)| match best {
+                        Some((_, best_score)) if best_score >= score => best,
+                        _ => Some((c, score)),
+                    },
+                )
+                .map(|(c, _)| c)
+        } else {
+            self.favorite(|c| ballot.get(&c).is_some_and(|&score| score > 0))
+        };
+        let Some(favorite) = favorite else {
+            return ballot;
+        };
+        let mut scores = ballot;
+        for (c, score) in scores.iter_mut() {
+            if *c != favorite {
+                *score = 0;
+            }
+        }
+        scores
+    }
+}
+
+impl Describe for Bullet {
+    fn describe(&self) -> Component {
+        let component = Component::new("bullet");
+        if self.favorites.is_empty() {
+            component
+        } else {
+            component.param("candidates", self.favorites.clone())
+        }
+    }
+}
@@ -1,7 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::{Component, Describe};
 use crate::core::{Id, Ordinal, Tactic};
 
 /// The burial tactic buries some leading candidate to hopefully knock them out early in the methods. The `Vec<Id>` specifies the candidates to bury, with the new ranking being `(ballot - buried) + buried`.
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Burial {
     burials: Vec<Id>,
 }
@@ -22,3 +25,9 @@ impl Tactic<Ordinal> for Burial {
         Ordinal(ranking)
     }
 }
+
+impl Describe for Burial {
+    fn describe(&self) -> Component {
+        Component::new("burial").param("candidates", self.burials.clone())
+    }
+}
@@ -1,7 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::{Component, Describe};
 use crate::core::{Id, Ordinal, Tactic};
 
 /// The compromise tactic places candidates that are more likely to win in ahead of the true voters preferences. The `Vec<Id>` specifies the electable candidates (in order), with the new ranking being `compromise + (ballot - compromise)`.
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Compromise {
     compromises: Vec<Id>,
 }
@@ -23,3 +26,9 @@ impl Tactic<Ordinal> for Compromise {
         Ordinal(ranking)
     }
 }
+
+impl Describe for Compromise {
+    fn describe(&self) -> Component {
+        Component::new("compromise").param("candidates", self.compromises.clone())
+    }
+}
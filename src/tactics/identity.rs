@@ -1,7 +1,8 @@
+use crate::config::{Component, Describe};
 use crate::core::{Ballot, Tactic};
 
 /// The identity tactic does nothing and returns the honest ballot.
-#[derive(Debug)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Identity;
 
 impl<B: Ballot> Tactic<B> for Identity {
@@ -10,3 +11,9 @@ impl<B: Ballot> Tactic<B> for Identity {
         ballot
     }
 }
+
+impl Describe for Identity {
+    fn describe(&self) -> Component {
+        Component::new("identity")
+    }
+}
@@ -1,9 +1,11 @@
 //! A collection of `tactics` that can be used to represent the tactics (and thus strategies) of voters in an election.
+mod bullet;
 mod burial;
 mod compromise;
 mod identity;
 mod pushover;
 
+pub use bullet::Bullet;
 pub use burial::Burial;
 pub use compromise::Compromise;
 pub use identity::Identity;
@@ -12,10 +14,10 @@ pub use pushover::Pushover;
 #[cfg(test)]
 mod tests {
     use crate::prelude::{
-        tactics::{Burial, Compromise, Identity, Pushover},
+        tactics::{Bullet, Burial, Compromise, Identity, Pushover},
         *,
     };
-    use std::collections::BTreeSet;
+    use std::collections::{BTreeMap, BTreeSet};
 
     #[test]
     fn identity_tactic() {
@@ -44,4 +46,55 @@ mod tests {
         let ordinal = Ordinal(vec![0, 1, 2]);
         assert_eq!(pushover.apply(ordinal), Ordinal(vec![0, 2, 1]));
     }
+
+    #[test]
+    fn bullet_tactic() {
+        let bullet = Bullet::default();
+        let ordinal = Ordinal(vec![0, 2, 1]);
+        assert_eq!(bullet.apply(ordinal.clone()), Ordinal(vec![0]));
+        let cardinal = Cardinal(BTreeMap::from([(0, 2), (1, 5), (2, 5)]));
+        assert_eq!(
+            bullet.apply(cardinal.clone()),
+            Cardinal(BTreeMap::from([(0, 0), (1, 5), (2, 0)]))
+        );
+        // Approval ballots do not say which approved candidate is the favorite.
+        let nominal = Nominal(BTreeSet::from([1, 2]));
+        assert_eq!(bullet.apply(nominal.clone()), nominal);
+
+        let bullet = Bullet::new(vec![3, 2]);
+        assert_eq!(bullet.apply(ordinal), Ordinal(vec![2]));
+        assert_eq!(
+            bullet.apply(cardinal),
+            Cardinal(BTreeMap::from([(0, 0), (1, 0), (2, 5)]))
+        );
+        assert_eq!(bullet.apply(nominal), Nominal(BTreeSet::from([2])));
+        let nominal = Nominal(BTreeSet::from([0, 1]));
+        assert_eq!(bullet.apply(nominal.clone()), nominal);
+    }
+
+    #[test]
+    fn bullet_budgeting() {
+        let candidates: Vec<Candidate> = ["A", "B", "C"]
+            .iter()
+            .enumerate()
+            .map(|(id, name)| Candidate::new(id, name, None, None))
+            .collect();
+        // Four voters approve of A and B, and two approve of A and C.
+        let ballots: Vec<Nominal> = (0..6)
+            .map(|i| match i {
+                0..4 => Nominal(BTreeSet::from([0, 1])),
+                _ => Nominal(BTreeSet::from([0, 2])),
+            })
+            .collect();
+        let method = methods::PhragmenBudgeting::new(vec![1.0; 3], 2.0);
+        let outcome = method.outcome(&candidates, ballots.clone().into());
+        assert_eq!(outcome, MultiWinner::seats(&candidates, &[0, 1]));
+        // Bullet voting for A leaves nobody supporting the other projects, so the rest of the budget goes unspent.
+        let bullets: Vec<Nominal> = ballots
+            .into_iter()
+            .map(|b| Bullet::new(vec![0]).apply(b))
+            .collect();
+        let outcome = method.outcome(&candidates, bullets.into());
+        assert_eq!(outcome, MultiWinner::seats(&candidates, &[0]));
+    }
 }
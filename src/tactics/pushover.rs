@@ -1,7 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::{Component, Describe};
 use crate::core::{Id, Ordinal, Tactic};
 
 /// The pushover tactic places pushover candidates highly, not to get them elected, but to hopefully knock out stronger candidates in early rounds of voting before losing to their true preferences. The `Vec<Id>` specifies the pushover candidates (in order), with the new ranking being `preferred + pushover + others`.
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Pushover {
     /// The preferred candidates.
     pub preferred: Vec<Id>,
@@ -31,3 +34,11 @@ impl Tactic<Ordinal> for Pushover {
         Ordinal(ranking)
     }
 }
+
+impl Describe for Pushover {
+    fn describe(&self) -> Component {
+        Component::new("pushover")
+            .param("preferred", self.preferred.clone())
+            .param("pushover", self.pushover.clone())
+    }
+}